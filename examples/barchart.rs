@@ -95,7 +95,11 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .bar_width(5)
                 .bar_gap(3)
                 .style(Style::default().fg(Color::Green))
-                .value_style(Style::default().bg(Color::Green).modifier(Modifier::BOLD));
+                .value_style(
+                    Style::default()
+                        .bg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                );
             f.render_widget(barchart, chunks[0]);
 
             let barchart = BarChart::default()
@@ -105,7 +109,11 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .bar_width(7)
                 .bar_gap(0)
                 .value_style(Style::default().bg(Color::Red))
-                .label_style(Style::default().fg(Color::Cyan).modifier(Modifier::ITALIC));
+                .label_style(
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::ITALIC),
+                );
             f.render_widget(barchart, chunks[1]);
         })?;
 
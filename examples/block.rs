@@ -53,7 +53,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                 let title_style = Style::default()
                     .fg(Color::White)
                     .bg(Color::Red)
-                    .modifier(Modifier::BOLD);
+                    .add_modifier(Modifier::BOLD);
                 let block = Block::default()
                     .title("Styled title")
                     .title_style(title_style);
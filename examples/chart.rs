@@ -113,14 +113,18 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .block(
                     Block::default()
                         .title("Chart 1")
-                        .title_style(Style::default().fg(Color::Cyan).modifier(Modifier::BOLD))
+                        .title_style(
+                            Style::default()
+                                .fg(Color::Cyan)
+                                .add_modifier(Modifier::BOLD),
+                        )
                         .borders(Borders::ALL),
                 )
                 .x_axis(
                     Axis::default()
                         .title("X Axis")
                         .style(Style::default().fg(Color::Gray))
-                        .labels_style(Style::default().modifier(Modifier::ITALIC))
+                        .labels_style(Style::default().add_modifier(Modifier::ITALIC))
                         .bounds(app.window)
                         .labels(&x_labels),
                 )
@@ -128,7 +132,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                     Axis::default()
                         .title("Y Axis")
                         .style(Style::default().fg(Color::Gray))
-                        .labels_style(Style::default().modifier(Modifier::ITALIC))
+                        .labels_style(Style::default().add_modifier(Modifier::ITALIC))
                         .bounds([-20.0, 20.0])
                         .labels(&["-20", "0", "20"]),
                 )
@@ -145,14 +149,18 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .block(
                     Block::default()
                         .title("Chart 2")
-                        .title_style(Style::default().fg(Color::Cyan).modifier(Modifier::BOLD))
+                        .title_style(
+                            Style::default()
+                                .fg(Color::Cyan)
+                                .add_modifier(Modifier::BOLD),
+                        )
                         .borders(Borders::ALL),
                 )
                 .x_axis(
                     Axis::default()
                         .title("X Axis")
                         .style(Style::default().fg(Color::Gray))
-                        .labels_style(Style::default().modifier(Modifier::ITALIC))
+                        .labels_style(Style::default().add_modifier(Modifier::ITALIC))
                         .bounds([0.0, 5.0])
                         .labels(&["0", "2.5", "5.0"]),
                 )
@@ -160,7 +168,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                     Axis::default()
                         .title("Y Axis")
                         .style(Style::default().fg(Color::Gray))
-                        .labels_style(Style::default().modifier(Modifier::ITALIC))
+                        .labels_style(Style::default().add_modifier(Modifier::ITALIC))
                         .bounds([0.0, 5.0])
                         .labels(&["0", "2.5", "5.0"]),
                 )
@@ -177,14 +185,18 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .block(
                     Block::default()
                         .title("Chart 3")
-                        .title_style(Style::default().fg(Color::Cyan).modifier(Modifier::BOLD))
+                        .title_style(
+                            Style::default()
+                                .fg(Color::Cyan)
+                                .add_modifier(Modifier::BOLD),
+                        )
                         .borders(Borders::ALL),
                 )
                 .x_axis(
                     Axis::default()
                         .title("X Axis")
                         .style(Style::default().fg(Color::Gray))
-                        .labels_style(Style::default().modifier(Modifier::ITALIC))
+                        .labels_style(Style::default().add_modifier(Modifier::ITALIC))
                         .bounds([0.0, 50.0])
                         .labels(&["0", "25", "50"]),
                 )
@@ -192,7 +204,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                     Axis::default()
                         .title("Y Axis")
                         .style(Style::default().fg(Color::Gray))
-                        .labels_style(Style::default().modifier(Modifier::ITALIC))
+                        .labels_style(Style::default().add_modifier(Modifier::ITALIC))
                         .bounds([0.0, 5.0])
                         .labels(&["0", "2.5", "5"]),
                 )
@@ -5,19 +5,15 @@ mod util;
 
 use crate::demo::{ui, App};
 use argh::FromArgs;
-use crossterm::{
-    event::{self, Event as CEvent, KeyCode},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
+use crossterm::event::{self, Event as CEvent, KeyCode};
 use std::{
     error::Error,
-    io::{stdout, Write},
+    io::stdout,
     sync::mpsc,
     thread,
     time::{Duration, Instant},
 };
-use tui::{backend::CrosstermBackend, Terminal};
+use tui::{backend::CrosstermBackend, Terminal, TerminalOptions};
 
 enum Event<I> {
     Input(I),
@@ -38,14 +34,18 @@ struct Cli {
 fn main() -> Result<(), Box<dyn Error>> {
     let cli: Cli = argh::from_env();
 
-    enable_raw_mode()?;
-
-    let mut stdout = stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout());
 
-    let backend = CrosstermBackend::new(stdout);
-
-    let mut terminal = Terminal::new(backend)?;
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            restore_on_panic: true,
+            synchronized_output: true,
+            ..TerminalOptions::default()
+        },
+    )?;
+    terminal.enable_raw_mode()?;
+    terminal.enter_alternate_screen()?;
     terminal.hide_cursor()?;
 
     // Setup input handling
@@ -70,16 +70,11 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     let mut app = App::new("Crossterm Demo", cli.enhanced_graphics);
 
-    terminal.clear()?;
-
     loop {
         terminal.draw(|mut f| ui::draw(&mut f, &mut app))?;
         match rx.recv()? {
             Event::Input(event) => match event.code {
                 KeyCode::Char('q') => {
-                    disable_raw_mode()?;
-                    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-                    terminal.show_cursor()?;
                     break;
                 }
                 KeyCode::Char(c) => app.on_key(c),
@@ -68,7 +68,7 @@ where
             Style::default()
                 .fg(Color::Magenta)
                 .bg(Color::Black)
-                .modifier(Modifier::ITALIC | Modifier::BOLD),
+                .add_modifier(Modifier::ITALIC | Modifier::BOLD),
         )
         .label(&label)
         .ratio(app.progress);
@@ -113,7 +113,11 @@ where
             let tasks = app.tasks.items.iter().map(|i| Text::raw(*i));
             let tasks = List::new(tasks)
                 .block(Block::default().borders(Borders::ALL).title("List"))
-                .highlight_style(Style::default().fg(Color::Yellow).modifier(Modifier::BOLD))
+                .highlight_style(
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )
                 .highlight_symbol("> ");
             f.render_stateful_widget(tasks, chunks[0], &mut app.tasks.state);
 
@@ -151,7 +155,7 @@ where
                 Style::default()
                     .fg(Color::Black)
                     .bg(Color::Green)
-                    .modifier(Modifier::ITALIC),
+                    .add_modifier(Modifier::ITALIC),
             )
             .label_style(Style::default().fg(Color::Yellow))
             .style(Style::default().fg(Color::Green));
@@ -183,14 +187,18 @@ where
             .block(
                 Block::default()
                     .title("Chart")
-                    .title_style(Style::default().fg(Color::Cyan).modifier(Modifier::BOLD))
+                    .title_style(
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    )
                     .borders(Borders::ALL),
             )
             .x_axis(
                 Axis::default()
                     .title("X Axis")
                     .style(Style::default().fg(Color::Gray))
-                    .labels_style(Style::default().modifier(Modifier::ITALIC))
+                    .labels_style(Style::default().add_modifier(Modifier::ITALIC))
                     .bounds(app.signals.window)
                     .labels(&x_labels),
             )
@@ -198,7 +206,7 @@ where
                 Axis::default()
                     .title("Y Axis")
                     .style(Style::default().fg(Color::Gray))
-                    .labels_style(Style::default().modifier(Modifier::ITALIC))
+                    .labels_style(Style::default().add_modifier(Modifier::ITALIC))
                     .bounds([-20.0, 20.0])
                     .labels(&["-20", "0", "20"]),
             )
@@ -219,19 +227,23 @@ where
         Text::raw(" "),
         Text::styled("rainbow", Style::default().fg(Color::Blue)),
         Text::raw(".\nOh and if you didn't "),
-        Text::styled("notice", Style::default().modifier(Modifier::ITALIC)),
+        Text::styled("notice", Style::default().add_modifier(Modifier::ITALIC)),
         Text::raw(" you can "),
-        Text::styled("automatically", Style::default().modifier(Modifier::BOLD)),
+        Text::styled("automatically", Style::default().add_modifier(Modifier::BOLD)),
         Text::raw(" "),
-        Text::styled("wrap", Style::default().modifier(Modifier::REVERSED)),
+        Text::styled("wrap", Style::default().add_modifier(Modifier::REVERSED)),
         Text::raw(" your "),
-        Text::styled("text", Style::default().modifier(Modifier::UNDERLINED)),
+        Text::styled("text", Style::default().add_modifier(Modifier::UNDERLINED)),
         Text::raw(".\nOne more thing is that it should display unicode characters: 10€")
     ];
     let block = Block::default()
         .borders(Borders::ALL)
         .title("Footer")
-        .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::BOLD));
+        .title_style(
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        );
     let paragraph = Paragraph::new(text.iter()).block(block).wrap(true);
     f.render_widget(paragraph, area);
 }
@@ -247,7 +259,7 @@ where
     let up_style = Style::default().fg(Color::Green);
     let failure_style = Style::default()
         .fg(Color::Red)
-        .modifier(Modifier::RAPID_BLINK | Modifier::CROSSED_OUT);
+        .add_modifier(Modifier::RAPID_BLINK | Modifier::CROSSED_OUT);
     let header = ["Server", "Location", "Status"];
     let rows = app.servers.iter().map(|s| {
         let style = if s.status == "Up" {
@@ -101,7 +101,11 @@ fn main() -> Result<(), Box<dyn Error>> {
             let label = format!("{}/100", app.progress2);
             let gauge = Gauge::default()
                 .block(Block::default().title("Gauge4").borders(Borders::ALL))
-                .style(Style::default().fg(Color::Cyan).modifier(Modifier::ITALIC))
+                .style(
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::ITALIC),
+                )
                 .percent(app.progress4)
                 .label(&label);
             f.render_widget(gauge, chunks[3]);
@@ -100,7 +100,7 @@ fn main() -> Result<(), Box<dyn Error>> {
             let items = List::new(items)
                 .block(Block::default().borders(Borders::ALL).title("List"))
                 .style(style)
-                .highlight_style(style.fg(Color::LightGreen).modifier(Modifier::BOLD))
+                .highlight_style(style.fg(Color::LightGreen).add_modifier(Modifier::BOLD))
                 .highlight_symbol(">");
             f.render_stateful_widget(items, chunks[0], &mut app.items.state);
 
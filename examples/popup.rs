@@ -72,12 +72,12 @@ fn main() -> Result<(), Box<dyn Error>> {
                 Text::styled("This is a line\n", Style::default().bg(Color::Blue)),
                 Text::styled(
                     "This is a longer line\n",
-                    Style::default().modifier(Modifier::CROSSED_OUT),
+                    Style::default().add_modifier(Modifier::CROSSED_OUT),
                 ),
                 Text::styled(&long_line, Style::default().bg(Color::Green)),
                 Text::styled(
                     "This is a line\n",
-                    Style::default().fg(Color::Green).modifier(Modifier::ITALIC),
+                    Style::default().fg(Color::Green).add_modifier(Modifier::ITALIC),
                 ),
             ];
 
@@ -8,8 +8,8 @@ use crate::{
 };
 use argh::FromArgs;
 use std::{error::Error, io, time::Duration};
-use termion::{event::Key, input::MouseTerminal, raw::IntoRawMode, screen::AlternateScreen};
-use tui::{backend::TermionBackend, Terminal};
+use termion::{event::Key, input::MouseTerminal};
+use tui::{backend::TermionBackend, Terminal, TerminalOptions};
 
 /// Termion demo
 #[derive(Debug, FromArgs)]
@@ -30,11 +30,18 @@ fn main() -> Result<(), Box<dyn Error>> {
         ..Config::default()
     });
 
-    let stdout = io::stdout().into_raw_mode()?;
-    let stdout = MouseTerminal::from(stdout);
-    let stdout = AlternateScreen::from(stdout);
+    let stdout = MouseTerminal::from(io::stdout());
     let backend = TermionBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            restore_on_panic: true,
+            synchronized_output: true,
+            ..TerminalOptions::default()
+        },
+    )?;
+    terminal.enable_raw_mode()?;
+    terminal.enter_alternate_screen()?;
     terminal.hide_cursor()?;
 
     let mut app = App::new("Termion demo", cli.enhanced_graphics);
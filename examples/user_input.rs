@@ -1,12 +1,11 @@
-/// A simple example demonstrating how to handle user input. This is
-/// a bit out of the scope of the library as it does not provide any
-/// input handling out of the box. However, it may helps some to get
-/// started.
+/// A simple example demonstrating how to handle user input with the
+/// `Input` widget and its `InputState`.
 ///
 /// This is a very simple example:
 ///   * A input box always focused. Every character you type is registered
 ///   here
-///   * Pressing Backspace erases a character
+///   * The usual editing keys (arrows, Home, End, Backspace, Delete) are
+///   handled by the `Input` widget
 ///   * Pressing Enter pushes the current input in the history of previous
 ///   messages
 
@@ -14,21 +13,16 @@
 mod util;
 
 use crate::util::event::{Event, Events};
-use std::{
-    error::Error,
-    io::{self, Write},
-};
-use termion::{
-    cursor::Goto, event::Key, input::MouseTerminal, raw::IntoRawMode, screen::AlternateScreen,
-};
+use std::{error::Error, io};
+use termion::{event::Key, input::MouseTerminal, raw::IntoRawMode, screen::AlternateScreen};
 use tui::{
-    backend::TermionBackend,
+    backend::{CursorShape, TermionBackend},
+    event::KeyEvent,
     layout::{Constraint, Direction, Layout},
     style::{Color, Style},
-    widgets::{Block, Borders, List, Paragraph, Text},
+    widgets::{Block, Borders, Input, InputState, List, Paragraph, Text},
     Terminal,
 };
-use unicode_width::UnicodeWidthStr;
 
 enum InputMode {
     Normal,
@@ -38,7 +32,7 @@ enum InputMode {
 /// App holds the state of the application
 struct App {
     /// Current value of the input box
-    input: String,
+    input: InputState,
     /// Current input mode
     input_mode: InputMode,
     /// History of recorded messages
@@ -48,7 +42,7 @@ struct App {
 impl Default for App {
     fn default() -> App {
         App {
-            input: String::new(),
+            input: InputState::default(),
             input_mode: InputMode::Normal,
             messages: Vec::new(),
         }
@@ -93,11 +87,17 @@ fn main() -> Result<(), Box<dyn Error>> {
             let help_message = Paragraph::new(text.iter());
             f.render_widget(help_message, chunks[0]);
 
-            let text = [Text::raw(&app.input)];
-            let input = Paragraph::new(text.iter())
+            let input = Input::default()
                 .style(Style::default().fg(Color::Yellow))
                 .block(Block::default().borders(Borders::ALL).title("Input"));
-            f.render_widget(input, chunks[1]);
+            f.render_stateful_widget(input, chunks[1], &mut app.input);
+            // Put the cursor inside the input box while editing
+            if let InputMode::Editing = app.input_mode {
+                if let Some((x, y)) = app.input.cursor_position() {
+                    f.set_cursor(x, y);
+                    f.set_cursor_shape(CursorShape::BlinkingBar);
+                }
+            }
             let messages = app
                 .messages
                 .iter()
@@ -108,15 +108,6 @@ fn main() -> Result<(), Box<dyn Error>> {
             f.render_widget(messages, chunks[2]);
         })?;
 
-        // Put the cursor back inside the input box
-        write!(
-            terminal.backend_mut(),
-            "{}",
-            Goto(4 + app.input.width() as u16, 5)
-        )?;
-        // stdout is buffered, flush it to see the effect immediately when hitting backspace
-        io::stdout().flush().ok();
-
         // Handle input
         if let Event::Input(input) = events.next()? {
             match app.input_mode {
@@ -132,19 +123,15 @@ fn main() -> Result<(), Box<dyn Error>> {
                 },
                 InputMode::Editing => match input {
                     Key::Char('\n') => {
-                        app.messages.push(app.input.drain(..).collect());
-                    }
-                    Key::Char(c) => {
-                        app.input.push(c);
-                    }
-                    Key::Backspace => {
-                        app.input.pop();
+                        app.messages.push(app.input.take_value());
                     }
                     Key::Esc => {
                         app.input_mode = InputMode::Normal;
                         events.enable_exit_key();
                     }
-                    _ => {}
+                    key => {
+                        app.input.handle_key(&KeyEvent::from(key));
+                    }
                 },
             }
         }
@@ -1,6 +1,11 @@
 use std::{
     fmt,
     io::{self, Write},
+    ops::Range,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
 
 use crossterm::{
@@ -10,23 +15,52 @@ use crossterm::{
         Attribute as CAttribute, Color as CColor, Print, SetAttribute, SetBackgroundColor,
         SetForegroundColor,
     },
-    terminal::{self, Clear, ClearType},
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
 };
 
+#[cfg(unix)]
+use crate::backend::escape::{
+    Hyperlink, ScrollRegion, SetCursorShape, UnderlineColor, UnderlineDiff,
+    BEGIN_SYNCHRONIZED_UPDATE, END_SYNCHRONIZED_UPDATE,
+};
 use crate::backend::Backend;
-use crate::style::{Color, Modifier};
-use crate::{buffer::Cell, layout::Rect, style};
+#[cfg(unix)]
+use crate::backend::CursorShape;
+use crate::style::{Color, ColorDepth, Modifier};
+use crate::{buffer::Cell, layout::Rect};
 
 pub struct CrosstermBackend<W: Write> {
     buffer: W,
+    /// Whether raw mode is enabled
+    raw_mode: Arc<AtomicBool>,
+    /// Whether the alternate screen is displayed
+    alternate_screen: Arc<AtomicBool>,
+    /// Colors are converted to this depth before being drawn
+    color_depth: ColorDepth,
 }
 
 impl<W> CrosstermBackend<W>
 where
     W: Write,
 {
+    /// Creates a backend drawing to `buffer`, with the color depth detected from the
+    /// environment.
     pub fn new(buffer: W) -> CrosstermBackend<W> {
-        CrosstermBackend { buffer }
+        CrosstermBackend {
+            buffer,
+            raw_mode: Arc::new(AtomicBool::new(false)),
+            alternate_screen: Arc::new(AtomicBool::new(false)),
+            color_depth: ColorDepth::detect(),
+        }
+    }
+
+    pub fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    /// Overrides the detected color depth.
+    pub fn set_color_depth(&mut self, depth: ColorDepth) {
+        self.color_depth = depth;
     }
 }
 
@@ -54,7 +88,13 @@ where
         use fmt::Write;
 
         let mut string = String::with_capacity(content.size_hint().0 * 3);
-        let mut style = style::Style::default();
+        let mut fg = Color::Reset;
+        let mut bg = Color::Reset;
+        #[cfg(unix)]
+        let mut underline_color = Color::Reset;
+        #[cfg(unix)]
+        let mut link = None;
+        let mut modifier = Modifier::empty();
         let mut last_y = 0;
         let mut last_x = 0;
         let mut inst = 0;
@@ -65,31 +105,53 @@ where
             }
             last_x = x;
             last_y = y;
-            if cell.style.modifier != style.modifier {
+            if cell.modifier != modifier {
                 let diff = ModifierDiff {
-                    from: style.modifier,
-                    to: cell.style.modifier,
+                    from: modifier,
+                    to: cell.modifier,
                 };
                 diff.queue(&mut string)?;
                 inst += 1;
-                style.modifier = cell.style.modifier;
+                modifier = cell.modifier;
             }
-            if cell.style.fg != style.fg {
-                let color = CColor::from(cell.style.fg);
+            if cell.fg != fg {
+                let color = CColor::from(cell.fg.downsample(self.color_depth));
                 map_error(queue!(string, SetForegroundColor(color)))?;
-                style.fg = cell.style.fg;
+                fg = cell.fg;
                 inst += 1;
             }
-            if cell.style.bg != style.bg {
-                let color = CColor::from(cell.style.bg);
+            if cell.bg != bg {
+                let color = CColor::from(cell.bg.downsample(self.color_depth));
                 map_error(queue!(string, SetBackgroundColor(color)))?;
-                style.bg = cell.style.bg;
+                bg = cell.bg;
                 inst += 1;
             }
+            // The Windows console only draws plain underlines, with the foreground color, and
+            // has no hyperlinks
+            #[cfg(unix)]
+            {
+                if cell.underline_color != underline_color {
+                    let color = cell.underline_color.downsample(self.color_depth);
+                    map_error(queue!(string, Print(UnderlineColor(color))))?;
+                    underline_color = cell.underline_color;
+                    inst += 1;
+                }
+                if cell.link.as_deref() != link {
+                    link = cell.link.as_deref();
+                    map_error(queue!(string, Print(Hyperlink(link))))?;
+                    inst += 1;
+                }
+            }
 
-            string.push_str(&cell.symbol);
+            string.push_str(cell.symbol());
             inst += 1;
         }
+        #[cfg(unix)]
+        {
+            if link.is_some() {
+                map_error(queue!(string, Print(Hyperlink(None))))?;
+            }
+        }
 
         map_error(queue!(
             self.buffer,
@@ -117,10 +179,63 @@ where
         map_error(execute!(self.buffer, MoveTo(x, y)))
     }
 
+    #[cfg(unix)]
+    fn set_cursor_shape(&mut self, shape: CursorShape) -> io::Result<()> {
+        map_error(queue!(self.buffer, Print(SetCursorShape(shape))))
+    }
+
     fn clear(&mut self) -> io::Result<()> {
         map_error(execute!(self.buffer, Clear(ClearType::All)))
     }
 
+    fn enter_alternate_screen(&mut self) -> io::Result<()> {
+        map_error(execute!(self.buffer, EnterAlternateScreen))?;
+        self.alternate_screen.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn leave_alternate_screen(&mut self) -> io::Result<()> {
+        map_error(execute!(self.buffer, LeaveAlternateScreen))?;
+        self.alternate_screen.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        map_error(terminal::enable_raw_mode())?;
+        self.raw_mode.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        map_error(terminal::disable_raw_mode())?;
+        self.raw_mode.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// The returned function writes to the standard output as the writer of the backend can not
+    /// be shared.
+    fn restorer(&self) -> Box<dyn Fn() + Send + Sync> {
+        let raw_mode = self.raw_mode.clone();
+        let alternate_screen = self.alternate_screen.clone();
+        Box::new(move || {
+            let mut stdout = io::stdout();
+            if alternate_screen.swap(false, Ordering::SeqCst) {
+                execute!(stdout, LeaveAlternateScreen).ok();
+            }
+            execute!(stdout, Show).ok();
+            if raw_mode.swap(false, Ordering::SeqCst) {
+                terminal::disable_raw_mode().ok();
+            }
+        })
+    }
+
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        for _ in 0..n {
+            map_error(queue!(self.buffer, Print("\n")))?;
+        }
+        self.buffer.flush()
+    }
+
     fn size(&self) -> io::Result<Rect> {
         let (width, height) =
             terminal::size().map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
@@ -131,6 +246,34 @@ where
     fn flush(&mut self) -> io::Result<()> {
         self.buffer.flush()
     }
+
+    // The Windows console paints as it goes
+    #[cfg(unix)]
+    fn begin_synchronized_update(&mut self) -> io::Result<()> {
+        map_error(queue!(self.buffer, Print(BEGIN_SYNCHRONIZED_UPDATE)))
+    }
+
+    #[cfg(unix)]
+    fn end_synchronized_update(&mut self) -> io::Result<()> {
+        map_error(queue!(self.buffer, Print(END_SYNCHRONIZED_UPDATE)))
+    }
+
+    #[cfg(unix)]
+    fn supports_scroll_regions(&self) -> bool {
+        true
+    }
+
+    #[cfg(unix)]
+    fn scroll_region_up(&mut self, region: Range<u16>, amount: u16) -> io::Result<()> {
+        let lines = i32::from(amount);
+        map_error(queue!(self.buffer, Print(ScrollRegion { region, lines })))
+    }
+
+    #[cfg(unix)]
+    fn scroll_region_down(&mut self, region: Range<u16>, amount: u16) -> io::Result<()> {
+        let lines = -i32::from(amount);
+        map_error(queue!(self.buffer, Print(ScrollRegion { region, lines })))
+    }
 }
 
 fn map_error(error: crossterm::Result<()>) -> io::Result<()> {
@@ -189,9 +332,6 @@ impl ModifierDiff {
         if removed.contains(Modifier::ITALIC) {
             map_error(queue!(w, SetAttribute(CAttribute::NoItalic)))?;
         }
-        if removed.contains(Modifier::UNDERLINED) {
-            map_error(queue!(w, SetAttribute(CAttribute::NoUnderline)))?;
-        }
         if removed.contains(Modifier::DIM) {
             map_error(queue!(w, SetAttribute(CAttribute::NormalIntensity)))?;
         }
@@ -212,9 +352,6 @@ impl ModifierDiff {
         if added.contains(Modifier::ITALIC) {
             map_error(queue!(w, SetAttribute(CAttribute::Italic)))?;
         }
-        if added.contains(Modifier::UNDERLINED) {
-            map_error(queue!(w, SetAttribute(CAttribute::Underlined)))?;
-        }
         if added.contains(Modifier::DIM) {
             map_error(queue!(w, SetAttribute(CAttribute::Dim)))?;
         }
@@ -227,6 +364,11 @@ impl ModifierDiff {
         if added.contains(Modifier::RAPID_BLINK) {
             map_error(queue!(w, SetAttribute(CAttribute::RapidBlink)))?;
         }
+        let underline = UnderlineDiff {
+            from: self.from,
+            to: self.to,
+        };
+        map_error(queue!(w, Print(underline)))?;
 
         Ok(())
     }
@@ -242,7 +384,9 @@ impl ModifierDiff {
         if removed.contains(Modifier::BOLD) {
             map_error(queue!(w, SetAttribute(CAttribute::NormalIntensity)))?;
         }
-        if removed.contains(Modifier::UNDERLINED) {
+        // Every style of underline is drawn as a plain underline
+        let underlined = |m: Modifier| m.intersects(Modifier::ALL_UNDERLINES);
+        if underlined(self.from) && !underlined(self.to) {
             map_error(queue!(w, SetAttribute(CAttribute::NoUnderline)))?;
         }
 
@@ -250,7 +394,7 @@ impl ModifierDiff {
         if added.contains(Modifier::BOLD) {
             map_error(queue!(w, SetAttribute(CAttribute::Bold)))?;
         }
-        if added.contains(Modifier::UNDERLINED) {
+        if !underlined(self.from) && underlined(self.to) {
             map_error(queue!(w, SetAttribute(CAttribute::Underlined)))?;
         }
         Ok(())
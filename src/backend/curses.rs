@@ -3,7 +3,7 @@ use std::io;
 use crate::backend::Backend;
 use crate::buffer::Cell;
 use crate::layout::Rect;
-use crate::style::{Color, Modifier, Style};
+use crate::style::{Color, ColorDepth, Modifier};
 use crate::symbols::{bar, block};
 #[cfg(unix)]
 use crate::symbols::{line, DOT};
@@ -41,11 +41,9 @@ impl Backend for CursesBackend {
     {
         let mut last_col = 0;
         let mut last_row = 0;
-        let mut style = Style {
-            fg: Color::Reset,
-            bg: Color::Reset,
-            modifier: Modifier::empty(),
-        };
+        let mut fg = Color::Reset;
+        let mut bg = Color::Reset;
+        let mut modifier = Modifier::empty();
         let mut curses_style = CursesStyle {
             fg: easycurses::Color::White,
             bg: easycurses::Color::Black,
@@ -58,27 +56,27 @@ impl Backend for CursesBackend {
             }
             last_col = col;
             last_row = row;
-            if cell.style.modifier != style.modifier {
-                apply_modifier_diff(&mut self.curses.win, style.modifier, cell.style.modifier);
-                style.modifier = cell.style.modifier;
+            if cell.modifier != modifier {
+                apply_modifier_diff(&mut self.curses.win, modifier, cell.modifier);
+                modifier = cell.modifier;
             };
-            if cell.style.fg != style.fg {
+            if cell.fg != fg {
                 update_color = true;
-                if let Some(ccolor) = cell.style.fg.into() {
-                    style.fg = cell.style.fg;
+                if let Some(ccolor) = cell.fg.into() {
+                    fg = cell.fg;
                     curses_style.fg = ccolor;
                 } else {
-                    style.fg = Color::White;
+                    fg = Color::White;
                     curses_style.fg = easycurses::Color::White;
                 }
             };
-            if cell.style.bg != style.bg {
+            if cell.bg != bg {
                 update_color = true;
-                if let Some(ccolor) = cell.style.bg.into() {
-                    style.bg = cell.style.bg;
+                if let Some(ccolor) = cell.bg.into() {
+                    bg = cell.bg;
                     curses_style.bg = ccolor;
                 } else {
-                    style.bg = Color::Black;
+                    bg = Color::Black;
                     curses_style.bg = easycurses::Color::Black;
                 }
             };
@@ -87,7 +85,7 @@ impl Backend for CursesBackend {
                     .set_color_pair(easycurses::ColorPair::new(curses_style.fg, curses_style.bg));
             };
             update_color = false;
-            draw(&mut self.curses, cell.symbol.as_str());
+            draw(&mut self.curses, cell.symbol());
         }
         self.curses.win.attrset(pancurses::Attribute::Normal);
         self.curses.set_color_pair(easycurses::ColorPair::new(
@@ -119,6 +117,37 @@ impl Backend for CursesBackend {
         // self.curses.refresh();
         Ok(())
     }
+    /// curses always draws to the alternate screen, it is left by suspending curses mode
+    fn enter_alternate_screen(&mut self) -> io::Result<()> {
+        self.curses.win.refresh();
+        Ok(())
+    }
+    fn leave_alternate_screen(&mut self) -> io::Result<()> {
+        pancurses::endwin();
+        Ok(())
+    }
+    /// Unlike the other backends, the echo of the input is left as it is (see `pancurses::noecho`).
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        pancurses::raw();
+        Ok(())
+    }
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        pancurses::noraw();
+        Ok(())
+    }
+    fn restorer(&self) -> Box<dyn Fn() + Send + Sync> {
+        Box::new(|| {
+            pancurses::endwin();
+        })
+    }
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        // curses always manages the whole screen, there is no scrollback to push lines to.
+        let (row, col) = self.curses.get_cursor_rc();
+        let (nrows, _) = self.curses.get_row_col_count();
+        self.curses
+            .move_rc(std::cmp::min(row + i32::from(n), nrows - 1), col);
+        Ok(())
+    }
     fn size(&self) -> Result<Rect, io::Error> {
         let (nrows, ncols) = self.curses.get_row_col_count();
         Ok(Rect::new(0, 0, ncols as u16, nrows as u16))
@@ -217,17 +246,26 @@ impl From<Color> for Option<easycurses::Color> {
             Color::Cyan | Color::LightCyan => Some(easycurses::Color::Cyan),
             Color::White | Color::Gray | Color::DarkGray => Some(easycurses::Color::White),
             Color::Blue | Color::LightBlue => Some(easycurses::Color::Blue),
-            Color::Indexed(_) => None,
-            Color::Rgb(_, _, _) => None,
+            Color::Indexed(_) | Color::Rgb(_, _, _) => color.downsample(ColorDepth::Ansi16).into(),
         }
     }
 }
 
 fn apply_modifier_diff(win: &mut pancurses::Window, from: Modifier, to: Modifier) {
+    let (from, to) = (plain_underline(from), plain_underline(to));
     remove_modifier(win, from - to);
     add_modifier(win, to - from);
 }
 
+/// Replaces every style of underline by `UNDERLINED`, the only one curses draws.
+fn plain_underline(modifier: Modifier) -> Modifier {
+    if modifier.intersects(Modifier::ALL_UNDERLINES) {
+        (modifier - Modifier::ALL_UNDERLINES) | Modifier::UNDERLINED
+    } else {
+        modifier
+    }
+}
+
 fn remove_modifier(win: &mut pancurses::Window, remove: Modifier) {
     if remove.contains(Modifier::BOLD) {
         win.attroff(pancurses::Attribute::Bold);
@@ -0,0 +1,186 @@
+//! Escape sequences that neither termion nor crossterm provide: the extended underlines, their
+//! color, hyperlinks, synchronized updates, scroll regions and cursor shapes.
+use std::fmt;
+use std::ops::Range;
+
+use super::CursorShape;
+
+use crate::style::{Color, Modifier};
+
+/// Changes the underline drawn for the `from` modifiers to the one drawn for `to`.
+///
+/// The style of the underline is the sub-parameter of `4` (`ESC [ 4 : n m`), terminals only draw
+/// one underline per cell so the most specific one is written.
+#[derive(Debug, Clone, Copy)]
+pub struct UnderlineDiff {
+    pub from: Modifier,
+    pub to: Modifier,
+}
+
+fn underline(modifier: Modifier) -> Option<u8> {
+    if modifier.contains(Modifier::UNDERCURLED) {
+        Some(3)
+    } else if modifier.contains(Modifier::UNDERDOTTED) {
+        Some(4)
+    } else if modifier.contains(Modifier::UNDERDASHED) {
+        Some(5)
+    } else if modifier.contains(Modifier::DOUBLE_UNDERLINED) {
+        Some(2)
+    } else if modifier.contains(Modifier::UNDERLINED) {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+impl fmt::Display for UnderlineDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let to = underline(self.to);
+        if underline(self.from) == to {
+            return Ok(());
+        }
+        match to {
+            None => write!(f, "\u{1b}[24m"),
+            Some(1) => write!(f, "\u{1b}[4m"),
+            Some(n) => write!(f, "\u{1b}[4:{}m", n),
+        }
+    }
+}
+
+/// Sets the color of the underlines (`ESC [ 58 ; ... m`), `Reset` drawing them with the
+/// foreground color.
+#[derive(Debug, Clone, Copy)]
+pub struct UnderlineColor(pub Color);
+
+impl fmt::Display for UnderlineColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let index = match self.0 {
+            Color::Reset => return write!(f, "\u{1b}[59m"),
+            Color::Rgb(r, g, b) => return write!(f, "\u{1b}[58;2;{};{};{}m", r, g, b),
+            Color::Indexed(i) => i,
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::Gray => 7,
+            Color::DarkGray => 8,
+            Color::LightRed => 9,
+            Color::LightGreen => 10,
+            Color::LightYellow => 11,
+            Color::LightBlue => 12,
+            Color::LightMagenta => 13,
+            Color::LightCyan => 14,
+            Color::White => 15,
+        };
+        write!(f, "\u{1b}[58;5;{}m", index)
+    }
+}
+
+/// Starts a hyperlink to the given target (`ESC ] 8 ; ; target ESC \`), or ends the current one
+/// when there is none.
+#[derive(Debug, Clone, Copy)]
+pub struct Hyperlink<'a>(pub Option<&'a str>);
+
+impl<'a> fmt::Display for Hyperlink<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("\u{1b}]8;;")?;
+        // Control characters would end the sequence early
+        for c in self.0.unwrap_or("").chars().filter(|c| !c.is_control()) {
+            write!(f, "{}", c)?;
+        }
+        f.write_str("\u{1b}\\")
+    }
+}
+
+/// Starts a synchronized update (DEC private mode 2026). Terminals which do not support it ignore
+/// the mode.
+pub const BEGIN_SYNCHRONIZED_UPDATE: &str = "\u{1b}[?2026h";
+
+/// Ends a synchronized update.
+pub const END_SYNCHRONIZED_UPDATE: &str = "\u{1b}[?2026l";
+
+/// Scrolls the lines in a region up by some lines (down when negative): the scroll region is set
+/// to those lines (`ESC [ top ; bottom r`), scrolled (`ESC [ n S` or `ESC [ n T`) and reset to the
+/// whole screen.
+#[derive(Debug, Clone)]
+pub struct ScrollRegion {
+    pub region: Range<u16>,
+    pub lines: i32,
+}
+
+impl fmt::Display for ScrollRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let direction = if self.lines > 0 { 'S' } else { 'T' };
+        write!(
+            f,
+            "\u{1b}[{};{}r\u{1b}[{}{}\u{1b}[r",
+            self.region.start + 1,
+            self.region.end,
+            self.lines.abs(),
+            direction
+        )
+    }
+}
+
+/// Sets the shape of the cursor (DECSCUSR, `ESC [ n SP q`).
+#[derive(Debug, Clone, Copy)]
+pub struct SetCursorShape(pub CursorShape);
+
+impl fmt::Display for SetCursorShape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let n = match self.0 {
+            CursorShape::Default => 0,
+            CursorShape::BlinkingBlock => 1,
+            CursorShape::SteadyBlock => 2,
+            CursorShape::BlinkingUnderline => 3,
+            CursorShape::SteadyUnderline => 4,
+            CursorShape::BlinkingBar => 5,
+            CursorShape::SteadyBar => 6,
+        };
+        write!(f, "\u{1b}[{} q", n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_underline_diff_writes_the_most_specific_underline() {
+        let diff = |from, to| UnderlineDiff { from, to }.to_string();
+        assert_eq!(diff(Modifier::empty(), Modifier::UNDERLINED), "\u{1b}[4m");
+        assert_eq!(
+            diff(
+                Modifier::UNDERLINED,
+                Modifier::UNDERLINED | Modifier::UNDERCURLED
+            ),
+            "\u{1b}[4:3m"
+        );
+        assert_eq!(diff(Modifier::UNDERDASHED, Modifier::BOLD), "\u{1b}[24m");
+        assert_eq!(diff(Modifier::BOLD, Modifier::ITALIC), "");
+        assert_eq!(
+            UnderlineColor(Color::LightRed).to_string(),
+            "\u{1b}[58;5;9m"
+        );
+        assert_eq!(UnderlineColor(Color::Reset).to_string(), "\u{1b}[59m");
+    }
+
+    #[test]
+    fn escape_hyperlink_strips_control_characters() {
+        assert_eq!(
+            Hyperlink(Some("https://example.com/\u{1b}\\\u{7}a")).to_string(),
+            "\u{1b}]8;;https://example.com/\\a\u{1b}\\"
+        );
+        assert_eq!(Hyperlink(None).to_string(), "\u{1b}]8;;\u{1b}\\");
+    }
+
+    #[test]
+    fn escape_scroll_region_uses_one_based_inclusive_lines() {
+        let scroll = |region, lines| ScrollRegion { region, lines }.to_string();
+        assert_eq!(scroll(2..6, 1), "\u{1b}[3;6r\u{1b}[1S\u{1b}[r");
+        assert_eq!(scroll(0..4, -2), "\u{1b}[1;4r\u{1b}[2T\u{1b}[r");
+    }
+}
@@ -1,4 +1,5 @@
 use std::io;
+use std::ops::Range;
 
 use crate::buffer::Cell;
 use crate::layout::Rect;
@@ -18,13 +19,29 @@ mod crossterm;
 #[cfg(feature = "crossterm")]
 pub use self::crossterm::CrosstermBackend;
 
+#[cfg(any(feature = "termion", feature = "crossterm"))]
+mod escape;
+
 #[cfg(feature = "curses")]
 mod curses;
 #[cfg(feature = "curses")]
 pub use self::curses::CursesBackend;
 
 mod test;
-pub use self::test::TestBackend;
+pub use self::test::{TestBackend, TestBackendModes};
+
+/// Shape of the text cursor, see [`Backend::set_cursor_shape`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorShape {
+    /// The shape configured by the user of the terminal
+    Default,
+    BlinkingBlock,
+    SteadyBlock,
+    BlinkingUnderline,
+    SteadyUnderline,
+    BlinkingBar,
+    SteadyBar,
+}
 
 pub trait Backend {
     fn draw<'a, I>(&mut self, content: I) -> Result<(), io::Error>
@@ -34,7 +51,64 @@ pub trait Backend {
     fn show_cursor(&mut self) -> Result<(), io::Error>;
     fn get_cursor(&mut self) -> Result<(u16, u16), io::Error>;
     fn set_cursor(&mut self, x: u16, y: u16) -> Result<(), io::Error>;
+    /// Changes the shape of the cursor. Backends unable to do so keep the current one.
+    fn set_cursor_shape(&mut self, _shape: CursorShape) -> Result<(), io::Error> {
+        Ok(())
+    }
     fn clear(&mut self) -> Result<(), io::Error>;
+    /// Switches to the alternate screen buffer, leaving the content of the main screen untouched.
+    /// Backends which cannot do it do nothing.
+    fn enter_alternate_screen(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+    /// Switches back to the main screen buffer.
+    fn leave_alternate_screen(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+    /// Enables raw mode: the input is passed to the application as it is typed instead of line by
+    /// line. Backends which cannot do it do nothing.
+    fn enable_raw_mode(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+    /// Restores the line buffering of the input.
+    fn disable_raw_mode(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+    /// Returns a function bringing the terminal back to its original state (main screen, raw mode
+    /// disabled, visible cursor). It is meant to be called from a panic hook, where the backend
+    /// itself is out of reach. The default one does nothing.
+    fn restorer(&self) -> Box<dyn Fn() + Send + Sync> {
+        Box::new(|| {})
+    }
+    /// Inserts `n` line breaks at the cursor position, scrolling the screen up when the cursor
+    /// reaches the bottom of the screen.
+    fn append_lines(&mut self, n: u16) -> Result<(), io::Error>;
     fn size(&self) -> Result<Rect, io::Error>;
     fn flush(&mut self) -> Result<(), io::Error>;
+    /// Starts a synchronized update: the terminal keeps displaying the previous frame until
+    /// [`Backend::end_synchronized_update`] is called instead of painting a partially drawn one.
+    /// Backends which cannot do it do nothing.
+    fn begin_synchronized_update(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+    /// Ends a synchronized update, the terminal paints everything drawn since it started.
+    fn end_synchronized_update(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+    /// Whether the backend implements [`Backend::scroll_region_up`] and
+    /// [`Backend::scroll_region_down`], letting the terminal move some lines instead of drawing
+    /// them again.
+    fn supports_scroll_regions(&self) -> bool {
+        false
+    }
+    /// Scrolls the lines in `region` up by `amount` lines, the lines scrolled into view at the
+    /// bottom of the region being blank with the default colors.
+    fn scroll_region_up(&mut self, _region: Range<u16>, _amount: u16) -> Result<(), io::Error> {
+        Ok(())
+    }
+    /// Scrolls the lines in `region` down by `amount` lines, the lines scrolled into view at the
+    /// top of the region being blank with the default colors.
+    fn scroll_region_down(&mut self, _region: Range<u16>, _amount: u16) -> Result<(), io::Error> {
+        Ok(())
+    }
 }
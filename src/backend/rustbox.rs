@@ -2,9 +2,9 @@ use crate::{
     backend::Backend,
     buffer::Cell,
     layout::Rect,
-    style::{Color, Modifier},
+    style::{Color, ColorDepth, Modifier},
 };
-use std::io;
+use std::io::{self, Write};
 
 pub struct RustboxBackend {
     rustbox: rustbox::RustBox,
@@ -34,10 +34,10 @@ impl Backend for RustboxBackend {
             self.rustbox.print(
                 x as usize,
                 y as usize,
-                cell.style.modifier.into(),
-                cell.style.fg.into(),
-                cell.style.bg.into(),
-                &cell.symbol,
+                cell.modifier.into(),
+                cell.fg.into(),
+                cell.bg.into(),
+                cell.symbol(),
             );
         }
         Ok(())
@@ -59,6 +59,33 @@ impl Backend for RustboxBackend {
         self.rustbox.clear();
         Ok(())
     }
+    // rustbox enables raw mode and switches to the alternate screen on initialization, and
+    // restores the terminal when it is dropped.
+    fn enter_alternate_screen(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+    fn leave_alternate_screen(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+    fn enable_raw_mode(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+    fn disable_raw_mode(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+    /// Raw mode is only disabled when the `RustBox` instance is dropped, which happens after the
+    /// panic hook ran, so the returned function only switches back to the main screen.
+    fn restorer(&self) -> Box<dyn Fn() + Send + Sync> {
+        Box::new(|| {
+            let mut stdout = io::stdout();
+            write!(stdout, "\x1b[?1049l\x1b[?25h").ok();
+            stdout.flush().ok();
+        })
+    }
+    fn append_lines(&mut self, _n: u16) -> Result<(), io::Error> {
+        // rustbox always manages the whole screen, there is no scrollback to push lines to.
+        Ok(())
+    }
     fn size(&self) -> Result<Rect, io::Error> {
         let term_width = self.rustbox.width();
         let term_height = self.rustbox.height();
@@ -84,10 +111,6 @@ impl Backend for RustboxBackend {
     }
 }
 
-fn rgb_to_byte(r: u8, g: u8, b: u8) -> u16 {
-    u16::from((r & 0xC0) + ((g & 0xE0) >> 2) + ((b & 0xE0) >> 5))
-}
-
 impl Into<rustbox::Color> for Color {
     fn into(self) -> rustbox::Color {
         match self {
@@ -101,7 +124,7 @@ impl Into<rustbox::Color> for Color {
             Color::White => rustbox::Color::White,
             Color::Blue | Color::LightBlue => rustbox::Color::Blue,
             Color::Indexed(i) => rustbox::Color::Byte(u16::from(i)),
-            Color::Rgb(r, g, b) => rustbox::Color::Byte(rgb_to_byte(r, g, b)),
+            Color::Rgb(_, _, _) => self.downsample(ColorDepth::Ansi256).into(),
         }
     }
 }
@@ -112,7 +135,7 @@ impl Into<rustbox::Style> for Modifier {
         if self.contains(Modifier::BOLD) {
             result.insert(rustbox::RB_BOLD);
         }
-        if self.contains(Modifier::UNDERLINED) {
+        if self.intersects(Modifier::ALL_UNDERLINES) {
             result.insert(rustbox::RB_UNDERLINE);
         }
         if self.contains(Modifier::REVERSED) {
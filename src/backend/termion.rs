@@ -1,25 +1,57 @@
 use std::fmt;
 use std::io;
 use std::io::Write;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 
-use super::Backend;
+use termion::raw::{IntoRawMode, RawTerminal};
+
+use super::escape::{
+    Hyperlink, ScrollRegion, SetCursorShape, UnderlineColor, UnderlineDiff,
+    BEGIN_SYNCHRONIZED_UPDATE, END_SYNCHRONIZED_UPDATE,
+};
+use super::{Backend, CursorShape};
 use crate::buffer::Cell;
 use crate::layout::Rect;
-use crate::style;
+use crate::style::{self, ColorDepth};
 
 pub struct TermionBackend<W>
 where
     W: Write,
 {
     stdout: W,
+    /// Holds the original settings of the terminal while raw mode is enabled, they are restored
+    /// when it is dropped.
+    raw_mode: Arc<Mutex<Option<RawTerminal<io::Sink>>>>,
+    /// Whether the alternate screen is displayed
+    alternate_screen: Arc<AtomicBool>,
+    /// Colors are converted to this depth before being drawn
+    color_depth: ColorDepth,
 }
 
 impl<W> TermionBackend<W>
 where
     W: Write,
 {
+    /// Creates a backend drawing to `stdout`, with the color depth detected from the
+    /// environment.
     pub fn new(stdout: W) -> TermionBackend<W> {
-        TermionBackend { stdout }
+        TermionBackend {
+            stdout,
+            raw_mode: Arc::new(Mutex::new(None)),
+            alternate_screen: Arc::new(AtomicBool::new(false)),
+            color_depth: ColorDepth::detect(),
+        }
+    }
+
+    pub fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    /// Overrides the detected color depth.
+    pub fn set_color_depth(&mut self, depth: ColorDepth) {
+        self.color_depth = depth;
     }
 }
 
@@ -70,6 +102,66 @@ where
         self.stdout.flush()
     }
 
+    fn set_cursor_shape(&mut self, shape: CursorShape) -> io::Result<()> {
+        write!(self.stdout, "{}", SetCursorShape(shape))
+    }
+
+    fn enter_alternate_screen(&mut self) -> io::Result<()> {
+        write!(self.stdout, "{}", termion::screen::ToAlternateScreen)?;
+        self.alternate_screen.store(true, Ordering::SeqCst);
+        self.stdout.flush()
+    }
+
+    fn leave_alternate_screen(&mut self) -> io::Result<()> {
+        write!(self.stdout, "{}", termion::screen::ToMainScreen)?;
+        self.alternate_screen.store(false, Ordering::SeqCst);
+        self.stdout.flush()
+    }
+
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        let mut raw_mode = self.raw_mode.lock().unwrap_or_else(|e| e.into_inner());
+        if raw_mode.is_none() {
+            // termion changes the settings of the terminal of the standard output (file
+            // descriptor 1), whatever the writer is.
+            *raw_mode = Some(io::sink().into_raw_mode()?);
+        }
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        // Dropping the raw terminal restores the original settings
+        self.raw_mode
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        Ok(())
+    }
+
+    /// The returned function writes to the standard output as the writer of the backend can not
+    /// be shared.
+    fn restorer(&self) -> Box<dyn Fn() + Send + Sync> {
+        let raw_mode = self.raw_mode.clone();
+        let alternate_screen = self.alternate_screen.clone();
+        Box::new(move || {
+            let mut stdout = io::stdout();
+            if alternate_screen.swap(false, Ordering::SeqCst) {
+                write!(stdout, "{}", termion::screen::ToMainScreen).ok();
+            }
+            write!(stdout, "{}", termion::cursor::Show).ok();
+            stdout.flush().ok();
+            // Dropping the raw terminal restores the original settings
+            raw_mode.lock().unwrap_or_else(|e| e.into_inner()).take();
+        })
+    }
+
+    /// Moves the cursor down `n` lines, scrolling the screen when needed
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        for _ in 0..n {
+            writeln!(self.stdout)?;
+        }
+        self.stdout.flush()
+    }
+
     fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
@@ -77,7 +169,11 @@ where
         use std::fmt::Write;
 
         let mut string = String::with_capacity(content.size_hint().0 * 3);
-        let mut style = style::Style::default();
+        let mut fg = style::Color::Reset;
+        let mut bg = style::Color::Reset;
+        let mut underline_color = style::Color::Reset;
+        let mut modifier = style::Modifier::empty();
+        let mut link = None;
         let mut last_y = 0;
         let mut last_x = 0;
         let mut inst = 0;
@@ -88,32 +184,46 @@ where
             }
             last_x = x;
             last_y = y;
-            if cell.style.modifier != style.modifier {
+            if cell.modifier != modifier {
                 write!(
                     string,
                     "{}",
                     ModifierDiff {
-                        from: style.modifier,
-                        to: cell.style.modifier
+                        from: modifier,
+                        to: cell.modifier
                     }
                 )
                 .unwrap();
-                style.modifier = cell.style.modifier;
+                modifier = cell.modifier;
+                inst += 1;
+            }
+            if cell.fg != fg {
+                write!(string, "{}", Fg(cell.fg.downsample(self.color_depth))).unwrap();
+                fg = cell.fg;
                 inst += 1;
             }
-            if cell.style.fg != style.fg {
-                write!(string, "{}", Fg(cell.style.fg)).unwrap();
-                style.fg = cell.style.fg;
+            if cell.bg != bg {
+                write!(string, "{}", Bg(cell.bg.downsample(self.color_depth))).unwrap();
+                bg = cell.bg;
                 inst += 1;
             }
-            if cell.style.bg != style.bg {
-                write!(string, "{}", Bg(cell.style.bg)).unwrap();
-                style.bg = cell.style.bg;
+            if cell.underline_color != underline_color {
+                let color = cell.underline_color.downsample(self.color_depth);
+                write!(string, "{}", UnderlineColor(color)).unwrap();
+                underline_color = cell.underline_color;
                 inst += 1;
             }
-            string.push_str(&cell.symbol);
+            if cell.link.as_deref() != link {
+                link = cell.link.as_deref();
+                write!(string, "{}", Hyperlink(link)).unwrap();
+                inst += 1;
+            }
+            string.push_str(cell.symbol());
             inst += 1;
         }
+        if link.is_some() {
+            write!(string, "{}", Hyperlink(None)).unwrap();
+        }
         write!(
             self.stdout,
             "{}{}{}{}",
@@ -133,6 +243,28 @@ where
     fn flush(&mut self) -> io::Result<()> {
         self.stdout.flush()
     }
+
+    fn begin_synchronized_update(&mut self) -> io::Result<()> {
+        write!(self.stdout, "{}", BEGIN_SYNCHRONIZED_UPDATE)
+    }
+
+    fn end_synchronized_update(&mut self) -> io::Result<()> {
+        write!(self.stdout, "{}", END_SYNCHRONIZED_UPDATE)
+    }
+
+    fn supports_scroll_regions(&self) -> bool {
+        true
+    }
+
+    fn scroll_region_up(&mut self, region: Range<u16>, amount: u16) -> io::Result<()> {
+        let lines = i32::from(amount);
+        write!(self.stdout, "{}", ScrollRegion { region, lines })
+    }
+
+    fn scroll_region_down(&mut self, region: Range<u16>, amount: u16) -> io::Result<()> {
+        let lines = -i32::from(amount);
+        write!(self.stdout, "{}", ScrollRegion { region, lines })
+    }
 }
 
 struct Fg(style::Color);
@@ -216,9 +348,6 @@ impl fmt::Display for ModifierDiff {
         if remove.contains(style::Modifier::ITALIC) {
             write!(f, "{}", termion::style::NoItalic)?;
         }
-        if remove.contains(style::Modifier::UNDERLINED) {
-            write!(f, "{}", termion::style::NoUnderline)?;
-        }
         if remove.contains(style::Modifier::DIM) {
             write!(f, "{}", termion::style::NoFaint)?;
 
@@ -247,9 +376,6 @@ impl fmt::Display for ModifierDiff {
         if add.contains(style::Modifier::ITALIC) {
             write!(f, "{}", termion::style::Italic)?;
         }
-        if add.contains(style::Modifier::UNDERLINED) {
-            write!(f, "{}", termion::style::Underline)?;
-        }
         if add.contains(style::Modifier::DIM) {
             write!(f, "{}", termion::style::Faint)?;
         }
@@ -259,6 +385,14 @@ impl fmt::Display for ModifierDiff {
         if add.contains(style::Modifier::SLOW_BLINK) || add.contains(style::Modifier::RAPID_BLINK) {
             write!(f, "{}", termion::style::Blink)?;
         }
+        write!(
+            f,
+            "{}",
+            UnderlineDiff {
+                from: self.from,
+                to: self.to
+            }
+        )?;
 
         Ok(())
     }
@@ -1,10 +1,37 @@
 use crate::{
-    backend::Backend,
+    backend::{Backend, CursorShape},
     buffer::{Buffer, Cell},
     layout::Rect,
 };
-use std::{fmt::Write, io};
-use unicode_width::UnicodeWidthStr;
+use std::{
+    fmt::Write,
+    io,
+    ops::Range,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// Whether a [`TestBackend`] displays the alternate screen and is in raw mode. It outlives the
+/// backend, to check that a terminal is restored once dropped or after a panic.
+#[derive(Debug, Clone, Default)]
+pub struct TestBackendModes {
+    alternate_screen: Arc<AtomicBool>,
+    raw_mode: Arc<AtomicBool>,
+}
+
+impl TestBackendModes {
+    /// Whether the alternate screen is displayed
+    pub fn is_alternate_screen(&self) -> bool {
+        self.alternate_screen.load(Ordering::SeqCst)
+    }
+
+    /// Whether raw mode is enabled
+    pub fn is_raw_mode(&self) -> bool {
+        self.raw_mode.load(Ordering::SeqCst)
+    }
+}
 
 /// A backend used for the integration tests.
 #[derive(Debug)]
@@ -13,7 +40,21 @@ pub struct TestBackend {
     buffer: Buffer,
     height: u16,
     cursor: bool,
+    cursor_shape: CursorShape,
     pos: (u16, u16),
+    modes: TestBackendModes,
+    /// Whether a synchronized update is in progress
+    synchronized_update: bool,
+    /// Number of synchronized updates ended so far
+    synchronized_updates: usize,
+    /// Number of calls to draw made outside of a synchronized update
+    unsynchronized_draws: usize,
+    /// Number of cells drawn so far
+    drawn_cells: usize,
+    /// Number of calls to flush made so far
+    flushes: usize,
+    /// Cells of the lines scrolled out of the top of the screen
+    scrollback: Vec<Cell>,
 }
 
 /// Returns a string representation of the given buffer for debugging purpose.
@@ -25,11 +66,11 @@ fn buffer_view(buffer: &Buffer) -> String {
         view.push('"');
         for (x, c) in cells.iter().enumerate() {
             if skip == 0 {
-                view.push_str(&c.symbol);
+                view.push_str(c.symbol());
             } else {
-                overwritten.push((x, &c.symbol))
+                overwritten.push((x, c.symbol()))
             }
-            skip = std::cmp::max(skip, c.symbol.width()).saturating_sub(1);
+            skip = std::cmp::max(skip, c.width()).saturating_sub(1);
         }
         view.push('"');
         if !overwritten.is_empty() {
@@ -51,8 +92,16 @@ impl TestBackend {
             width,
             height,
             buffer: Buffer::empty(Rect::new(0, 0, width, height)),
-            cursor: false,
+            cursor: true,
+            cursor_shape: CursorShape::Default,
             pos: (0, 0),
+            modes: TestBackendModes::default(),
+            synchronized_update: false,
+            synchronized_updates: 0,
+            unsynchronized_draws: 0,
+            drawn_cells: 0,
+            flushes: 0,
+            scrollback: Vec::new(),
         }
     }
 
@@ -60,6 +109,62 @@ impl TestBackend {
         &self.buffer
     }
 
+    /// Whether the alternate screen is displayed
+    pub fn is_alternate_screen(&self) -> bool {
+        self.modes.is_alternate_screen()
+    }
+
+    /// Whether raw mode is enabled
+    pub fn is_raw_mode(&self) -> bool {
+        self.modes.is_raw_mode()
+    }
+
+    /// Returns a handle on the modes of the backend, which stays valid after it is dropped
+    pub fn modes(&self) -> TestBackendModes {
+        self.modes.clone()
+    }
+
+    /// Position of the cursor, if it is visible
+    pub fn cursor_position(&self) -> Option<(u16, u16)> {
+        if self.cursor {
+            Some(self.pos)
+        } else {
+            None
+        }
+    }
+
+    /// The lines scrolled out of the top of the screen, oldest first
+    pub fn scrollback(&self) -> Buffer {
+        let height = self.scrollback.len() / usize::from(self.width.max(1));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, self.width, height as u16));
+        buffer.content.clone_from_slice(&self.scrollback);
+        buffer
+    }
+
+    pub fn cursor_shape(&self) -> CursorShape {
+        self.cursor_shape
+    }
+
+    /// Number of synchronized updates which have been started and ended
+    pub fn synchronized_updates(&self) -> usize {
+        self.synchronized_updates
+    }
+
+    /// Number of calls to [`Backend::draw`] made outside of a synchronized update
+    pub fn unsynchronized_draws(&self) -> usize {
+        self.unsynchronized_draws
+    }
+
+    /// Number of cells passed to [`Backend::draw`] so far
+    pub fn drawn_cells(&self) -> usize {
+        self.drawn_cells
+    }
+
+    /// Number of calls to [`Backend::flush`] made so far
+    pub fn flushes(&self) -> usize {
+        self.flushes
+    }
+
     pub fn assert_buffer(&self, expected: &Buffer) {
         assert_eq!(expected.area, self.buffer.area);
         let diff = expected.diff(&self.buffer);
@@ -104,10 +209,12 @@ impl Backend for TestBackend {
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
     {
+        if !self.synchronized_update {
+            self.unsynchronized_draws += 1;
+        }
         for (x, y, c) in content {
-            let cell = self.buffer.get_mut(x, y);
-            cell.symbol = c.symbol.clone();
-            cell.style = c.style;
+            *self.buffer.get_mut(x, y) = c.clone();
+            self.drawn_cells += 1;
         }
         Ok(())
     }
@@ -131,15 +238,99 @@ impl Backend for TestBackend {
         Ok(())
     }
 
+    fn set_cursor_shape(&mut self, shape: CursorShape) -> Result<(), io::Error> {
+        self.cursor_shape = shape;
+        Ok(())
+    }
+
     fn clear(&mut self) -> Result<(), io::Error> {
         Ok(())
     }
 
+    fn enter_alternate_screen(&mut self) -> Result<(), io::Error> {
+        self.modes.alternate_screen.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<(), io::Error> {
+        self.modes.alternate_screen.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn enable_raw_mode(&mut self) -> Result<(), io::Error> {
+        self.modes.raw_mode.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> Result<(), io::Error> {
+        self.modes.raw_mode.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn restorer(&self) -> Box<dyn Fn() + Send + Sync> {
+        let modes = self.modes.clone();
+        Box::new(move || {
+            modes.alternate_screen.store(false, Ordering::SeqCst);
+            modes.raw_mode.store(false, Ordering::SeqCst);
+        })
+    }
+
+    fn append_lines(&mut self, n: u16) -> Result<(), io::Error> {
+        let area = self.buffer.area;
+        for _ in 0..n {
+            if self.pos.1 + 1 < area.height {
+                self.pos.1 += 1;
+            } else {
+                // Scroll the whole screen up by one line
+                let line = self.buffer.content.drain(..area.width as usize);
+                self.scrollback.extend(line);
+                self.buffer
+                    .content
+                    .resize(area.area() as usize, Cell::default());
+            }
+        }
+        Ok(())
+    }
+
     fn size(&self) -> Result<Rect, io::Error> {
         Ok(Rect::new(0, 0, self.width, self.height))
     }
 
     fn flush(&mut self) -> Result<(), io::Error> {
+        self.flushes += 1;
+        Ok(())
+    }
+
+    fn begin_synchronized_update(&mut self) -> Result<(), io::Error> {
+        assert!(
+            !self.synchronized_update,
+            "a synchronized update is already in progress"
+        );
+        self.synchronized_update = true;
+        Ok(())
+    }
+
+    fn end_synchronized_update(&mut self) -> Result<(), io::Error> {
+        assert!(
+            self.synchronized_update,
+            "no synchronized update in progress"
+        );
+        self.synchronized_update = false;
+        self.synchronized_updates += 1;
+        Ok(())
+    }
+
+    fn supports_scroll_regions(&self) -> bool {
+        true
+    }
+
+    fn scroll_region_up(&mut self, region: Range<u16>, amount: u16) -> Result<(), io::Error> {
+        self.buffer.scroll_rows(region, i32::from(amount));
+        Ok(())
+    }
+
+    fn scroll_region_down(&mut self, region: Range<u16>, amount: u16) -> Result<(), io::Error> {
+        self.buffer.scroll_rows(region, -i32::from(amount));
         Ok(())
     }
 }
@@ -1,63 +1,195 @@
 use crate::{
     layout::Rect,
     style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    theme::Theme,
 };
-use std::cmp::min;
+use std::cmp::{min, Reverse};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::sync::Arc;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+/// Number of shifts tried for each run of changed lines by `Buffer::vertical_shifts`.
+const MAX_SHIFT_CANDIDATES: usize = 4;
+
+/// Graphemes up to this length in bytes are stored in the cells themselves, which covers all the
+/// single characters and most clusters.
+const INLINE_SYMBOL_LEN: usize = 22;
+
+/// The grapheme drawn by a cell.
+///
+/// Longer clusters, such as emoji sequences, are allocated when they are set and shared by the
+/// copies of the cell, so that copying or comparing cells never allocates.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Symbol {
+    Inline {
+        len: u8,
+        bytes: [u8; INLINE_SYMBOL_LEN],
+    },
+    Shared(Arc<str>),
+}
+
+impl Symbol {
+    fn new(s: &str) -> Symbol {
+        if s.len() <= INLINE_SYMBOL_LEN {
+            // The unused bytes stay zeroed so that symbols can be compared as a whole
+            let mut bytes = [0; INLINE_SYMBOL_LEN];
+            bytes[..s.len()].copy_from_slice(s.as_bytes());
+            Symbol::Inline {
+                len: s.len() as u8,
+                bytes,
+            }
+        } else {
+            Symbol::Shared(Arc::from(s))
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        match *self {
+            Symbol::Inline { len, ref bytes } => std::str::from_utf8(&bytes[..len as usize])
+                .expect("inline symbols are copied from a str"),
+            Symbol::Shared(ref s) => s,
+        }
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
 /// A buffer cell
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Cells are small and do not allocate, except for the rare graphemes longer than a few
+/// characters: the symbol is read with [`Cell::symbol`] and its width in columns is computed once
+/// when it is set.
+#[derive(Debug, Clone, PartialEq, Hash)]
 pub struct Cell {
-    pub symbol: String,
-    pub style: Style,
+    symbol: Symbol,
+    /// Width of the symbol in columns
+    width: u8,
+    pub fg: Color,
+    pub bg: Color,
+    pub underline_color: Color,
+    pub modifier: Modifier,
+    /// Target of the hyperlink the cell belongs to
+    pub link: Option<Arc<str>>,
 }
 
 impl Cell {
+    /// A cell drawing `symbol` with the default colors and modifiers. The other attributes are
+    /// changed with the setters:
+    ///
+    /// ```
+    /// # use tui::buffer::Cell;
+    /// # use tui::style::Color;
+    /// let mut cell = Cell::new("x");
+    /// cell.set_fg(Color::Red);
+    /// assert_eq!(cell.symbol(), "x");
+    /// ```
+    pub fn new(symbol: &str) -> Cell {
+        let mut cell = Cell::default();
+        cell.set_symbol(symbol);
+        cell
+    }
+
+    /// The grapheme drawn by the cell.
+    pub fn symbol(&self) -> &str {
+        self.symbol.as_str()
+    }
+
+    /// Width of the symbol in columns.
+    pub(crate) fn width(&self) -> usize {
+        usize::from(self.width)
+    }
+
     pub fn set_symbol(&mut self, symbol: &str) -> &mut Cell {
-        self.symbol.clear();
-        self.symbol.push_str(symbol);
-        self
+        self.set_grapheme(symbol, symbol.width())
     }
 
     pub fn set_char(&mut self, ch: char) -> &mut Cell {
-        self.symbol.clear();
-        self.symbol.push(ch);
+        let mut bytes = [0; 4];
+        self.set_symbol(ch.encode_utf8(&mut bytes))
+    }
+
+    /// Sets a symbol whose width is already known.
+    fn set_grapheme(&mut self, symbol: &str, width: usize) -> &mut Cell {
+        self.symbol = Symbol::new(symbol);
+        self.width = width.min(usize::from(u8::MAX)) as u8;
         self
     }
 
     pub fn set_fg(&mut self, color: Color) -> &mut Cell {
-        self.style.fg = color;
+        self.fg = color;
         self
     }
 
     pub fn set_bg(&mut self, color: Color) -> &mut Cell {
-        self.style.bg = color;
+        self.bg = color;
+        self
+    }
+
+    pub fn set_underline_color(&mut self, color: Color) -> &mut Cell {
+        self.underline_color = color;
         self
     }
 
     pub fn set_modifier(&mut self, modifier: Modifier) -> &mut Cell {
-        self.style.modifier = modifier;
+        self.modifier = modifier;
+        self
+    }
+
+    pub fn set_link(&mut self, link: Option<Arc<str>>) -> &mut Cell {
+        self.link = link;
         self
     }
 
+    /// Applies the colors and modifiers set by the style, keeping the other ones.
     pub fn set_style(&mut self, style: Style) -> &mut Cell {
-        self.style = style;
+        if let Some(c) = style.fg {
+            self.fg = c;
+        }
+        if let Some(c) = style.bg {
+            self.bg = c;
+        }
+        if let Some(c) = style.underline_color {
+            self.underline_color = c;
+        }
+        self.modifier.insert(style.add_modifier);
+        self.modifier.remove(style.sub_modifier);
         self
     }
 
+    /// The style of the cell, with all the colors set.
+    pub fn style(&self) -> Style {
+        Style::default()
+            .fg(self.fg)
+            .bg(self.bg)
+            .underline_color(self.underline_color)
+            .add_modifier(self.modifier)
+    }
+
     pub fn reset(&mut self) {
-        self.symbol.clear();
-        self.symbol.push(' ');
-        self.style.reset();
+        *self = Cell::default();
     }
 }
 
 impl Default for Cell {
     fn default() -> Cell {
         Cell {
-            symbol: " ".into(),
-            style: Default::default(),
+            symbol: Symbol::new(" "),
+            width: 1,
+            fg: Color::Reset,
+            bg: Color::Reset,
+            underline_color: Color::Reset,
+            modifier: Modifier::empty(),
+            link: None,
         }
     }
 }
@@ -74,21 +206,18 @@ impl Default for Cell {
 /// ```
 /// use tui::buffer::{Buffer, Cell};
 /// use tui::layout::Rect;
-/// use tui::style::{Color, Style, Modifier};
+/// use tui::style::{Color, Style};
 ///
 /// let mut buf = Buffer::empty(Rect{x: 0, y: 0, width: 10, height: 5});
 /// buf.get_mut(0, 2).set_symbol("x");
-/// assert_eq!(buf.get(0, 2).symbol, "x");
+/// assert_eq!(buf.get(0, 2).symbol(), "x");
 /// buf.set_string(3, 0, "string", Style::default().fg(Color::Red).bg(Color::White));
-/// assert_eq!(buf.get(5, 0), &Cell{
-///     symbol: String::from("r"),
-///     style: Style {
-///         fg: Color::Red,
-///         bg: Color::White,
-///         modifier: Modifier::empty()
-///     }});
+/// assert_eq!(
+///     buf.get(5, 0),
+///     Cell::default().set_symbol("r").set_fg(Color::Red).set_bg(Color::White)
+/// );
 /// buf.get_mut(5, 0).set_char('x');
-/// assert_eq!(buf.get(5, 0).symbol, "x");
+/// assert_eq!(buf.get(5, 0).symbol(), "x");
 /// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct Buffer {
@@ -97,6 +226,8 @@ pub struct Buffer {
     /// The content of the buffer. The length of this Vec should always be equal to area.width *
     /// area.height
     pub content: Vec<Cell>,
+    /// Default styles of the widgets rendered to this buffer
+    theme: Theme,
 }
 
 impl Default for Buffer {
@@ -104,6 +235,7 @@ impl Default for Buffer {
         Buffer {
             area: Default::default(),
             content: Vec::new(),
+            theme: Theme::default(),
         }
     }
 }
@@ -117,12 +249,11 @@ impl Buffer {
 
     /// Returns a Buffer with all cells initialized with the attributes of the given Cell
     pub fn filled(area: Rect, cell: &Cell) -> Buffer {
-        let size = area.area() as usize;
-        let mut content = Vec::with_capacity(size);
-        for _ in 0..size {
-            content.push(cell.clone());
+        Buffer {
+            area,
+            content: vec![cell.clone(); area.area() as usize],
+            theme: Theme::default(),
         }
-        Buffer { area, content }
     }
 
     /// Returns a Buffer containing the given lines
@@ -286,7 +417,7 @@ impl Buffer {
                 break;
             }
 
-            self.content[index].set_symbol(s);
+            self.content[index].set_grapheme(s, width);
             self.content[index].set_style(style);
             // Reset following cells if multi-width (they would be hidden by the grapheme),
             for i in index + 1..index + width {
@@ -298,6 +429,76 @@ impl Buffer {
         (x_offset as u16, y)
     }
 
+    /// Print at most `width` columns of a span, drawn with its style applied on top of `style`.
+    /// The cells of a span with a link become a hyperlink to it.
+    pub fn set_span(
+        &mut self,
+        x: u16,
+        y: u16,
+        span: &Span,
+        width: u16,
+        style: Style,
+    ) -> (u16, u16) {
+        let end = self.set_stringn(x, y, &span.content, width as usize, style.patch(span.style));
+        if let Some(ref link) = span.link {
+            self.set_link(Rect::new(x, y, end.0 - x, 1), Some(link));
+        }
+        end
+    }
+
+    /// Print at most `width` columns of a line made of several spans, each span being drawn with
+    /// its style applied on top of `style`.
+    pub fn set_spans(
+        &mut self,
+        x: u16,
+        y: u16,
+        spans: &Spans,
+        width: u16,
+        style: Style,
+    ) -> (u16, u16) {
+        let mut remaining = width;
+        let mut x = x;
+        for span in &spans.0 {
+            if remaining == 0 {
+                break;
+            }
+            let (next, _) = self.set_span(x, y, span, remaining, style);
+            remaining = remaining.saturating_sub(next - x);
+            x = next;
+        }
+        (x, y)
+    }
+
+    /// Applies a style to all the cells of an area.
+    pub fn set_style(&mut self, area: Rect, style: Style) {
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                self.get_mut(x, y).set_style(style);
+            }
+        }
+    }
+
+    /// Makes all the cells of an area a hyperlink to `link`, or removes their hyperlink.
+    ///
+    /// ```rust
+    /// # use tui::buffer::Buffer;
+    /// # use tui::layout::Rect;
+    /// # use tui::style::Style;
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+    /// let (end, _) = buf.set_stringn(0, 0, "Cargo.toml", 20, Style::default());
+    /// buf.set_link(Rect::new(0, 0, end, 1), Some("file:///src/Cargo.toml"));
+    /// assert_eq!(buf.get(9, 0).link.as_deref(), Some("file:///src/Cargo.toml"));
+    /// assert_eq!(buf.get(10, 0).link, None);
+    /// ```
+    pub fn set_link(&mut self, area: Rect, link: Option<&str>) {
+        let link: Option<Arc<str>> = link.map(Arc::from);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                self.get_mut(x, y).set_link(link.clone());
+            }
+        }
+    }
+
     pub fn set_background(&mut self, area: Rect, color: Color) {
         for y in area.top()..area.bottom() {
             for x in area.left()..area.right() {
@@ -318,6 +519,15 @@ impl Buffer {
         self.area = area;
     }
 
+    /// The theme widgets apply their styles on top of.
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
     /// Reset all cells in the buffer
     pub fn reset(&mut self) {
         for c in &mut self.content {
@@ -359,7 +569,8 @@ impl Buffer {
     /// self to other.
     ///
     /// We're assuming that buffers are well-formed, that is no double-width cell is followed by
-    /// a non-blank cell.
+    /// a non-blank cell. Both buffers are expected to cover the same area and the returned
+    /// coordinates are global (offset by the buffer's area).
     ///
     /// # Multi-width characters handling:
     ///
@@ -386,28 +597,160 @@ impl Buffer {
     pub fn diff<'a>(&self, other: &'a Buffer) -> Vec<(u16, u16, &'a Cell)> {
         let previous_buffer = &self.content;
         let next_buffer = &other.content;
-        let width = self.area.width;
+        let width = self.area.width as usize;
 
         let mut updates: Vec<(u16, u16, &Cell)> = vec![];
+        if width == 0 {
+            return updates;
+        }
         // Cells invalidated by drawing/replacing preceeding multi-width characters:
         let mut invalidated: usize = 0;
         // Cells from the current buffer to skip due to preceeding multi-width characters taking their
         // place (the skipped cells should be blank anyway):
         let mut to_skip: usize = 0;
-        for (i, (current, previous)) in next_buffer.iter().zip(previous_buffer.iter()).enumerate() {
-            if (current != previous || invalidated > 0) && to_skip == 0 {
-                let x = i as u16 % width;
-                let y = i as u16 / width;
-                updates.push((x, y, &next_buffer[i]));
+        let rows = next_buffer.chunks(width).zip(previous_buffer.chunks(width));
+        for (row, (next_row, previous_row)) in rows.enumerate() {
+            // Most rows do not change between two frames, they are compared as a whole and only
+            // their last cells matter for the next row
+            if invalidated == 0 && to_skip == 0 && next_row == previous_row {
+                to_skip = next_row.last().map_or(0, |c| c.width().saturating_sub(1));
+                invalidated = next_row
+                    .iter()
+                    .rev()
+                    .take(u8::MAX as usize)
+                    .enumerate()
+                    .map(|(k, c)| c.width().saturating_sub(k + 1))
+                    .max()
+                    .unwrap_or(0);
+                continue;
             }
+            for (column, (current, previous)) in next_row.iter().zip(previous_row).enumerate() {
+                if (current != previous || invalidated > 0) && to_skip == 0 {
+                    let (x, y) = self.pos_of(row * width + column);
+                    updates.push((x, y, current));
+                }
 
-            to_skip = current.symbol.width().saturating_sub(1);
+                to_skip = current.width().saturating_sub(1);
 
-            let affected_width = std::cmp::max(current.symbol.width(), previous.symbol.width());
-            invalidated = std::cmp::max(affected_width, invalidated).saturating_sub(1);
+                let affected_width = std::cmp::max(current.width(), previous.width());
+                invalidated = std::cmp::max(affected_width, invalidated).saturating_sub(1);
+            }
         }
         updates
     }
+
+    /// Finds the runs of lines which moved vertically between this buffer and `other`, such as a
+    /// log pane scrolled by a few lines. Scrolling them on the screen before diffing leaves only
+    /// the lines scrolled into view to draw.
+    ///
+    /// A run is a block of consecutive changed lines and is scrolled as a whole, by the amount
+    /// matching the most cells of `other`: the cells outside of a narrower pane are then drawn
+    /// again by the diff. Only a few amounts are compared cell by cell, the ones moving the most
+    /// whole lines in place and scrolling by one line, so that the search stays linear in the
+    /// size of the buffer. Returns the lines of each run, in screen coordinates, and how many lines
+    /// it moved up (down when negative).
+    pub(crate) fn vertical_shifts(&self, other: &Buffer) -> Vec<(Range<u16>, i32)> {
+        let width = self.area.width as usize;
+        let mut shifts = vec![];
+        if width == 0 || self.area != other.area {
+            return shifts;
+        }
+        let previous: Vec<&[Cell]> = self.content.chunks(width).collect();
+        let next: Vec<&[Cell]> = other.content.chunks(width).collect();
+        let hash = |line: &&[Cell]| {
+            let mut hasher = DefaultHasher::new();
+            line.hash(&mut hasher);
+            hasher.finish()
+        };
+        let previous_hashes: Vec<u64> = previous.iter().map(hash).collect();
+        let next_hashes: Vec<u64> = next.iter().map(hash).collect();
+        let blank = Cell::default();
+        // Cells of the line `y` of `other` already on the screen once the line `from` (if any, the
+        // line is blank otherwise) moved there
+        let matching = |from: Option<usize>, y: usize| match from {
+            Some(from) => previous[from]
+                .iter()
+                .zip(next[y])
+                .filter(|(p, n)| p == n)
+                .count(),
+            None => next[y].iter().filter(|&n| *n == blank).count(),
+        };
+        let mut row = 0;
+        while row < next.len() {
+            let start = row;
+            while row < next.len() && previous[row] != next[row] {
+                row += 1;
+            }
+            if row - start < 2 {
+                row += 1;
+                continue;
+            }
+            // Moving lines by more than half of the run leaves most of it to draw anyway
+            let max_amount = ((row - start) / 2) as i32;
+            // The lines found whole elsewhere in the run vote for the shifts moving them in place,
+            // scrolling by a single line being always tried
+            let mut lines_at: HashMap<u64, Vec<usize>> = HashMap::new();
+            for (from, &hash) in previous_hashes.iter().enumerate().take(row).skip(start) {
+                lines_at.entry(hash).or_default().push(from);
+            }
+            let mut votes: HashMap<i32, usize> = HashMap::new();
+            votes.insert(1, 0);
+            votes.insert(-1, 0);
+            for (y, hash) in next_hashes.iter().enumerate().take(row).skip(start) {
+                for &from in lines_at.get(hash).into_iter().flatten() {
+                    let lines = from as i32 - y as i32;
+                    if lines != 0 && lines.abs() <= max_amount {
+                        *votes.entry(lines).or_default() += 1;
+                    }
+                }
+            }
+            let mut candidates: Vec<(i32, usize)> = votes.into_iter().collect();
+            candidates.sort_by_key(|&(lines, votes)| (Reverse(votes), lines.abs(), lines));
+            candidates.truncate(MAX_SHIFT_CANDIDATES);
+
+            let unshifted: usize = (start..row).map(|y| matching(Some(y), y)).sum();
+            let mut best = (0, unshifted);
+            for (lines, _) in candidates {
+                let matches = (start..row)
+                    .map(|y| {
+                        let from = Some(y as i32 + lines)
+                            .filter(|&from| from >= start as i32 && from < row as i32);
+                        matching(from.map(|from| from as usize), y)
+                    })
+                    .sum();
+                if matches > best.1 {
+                    best = (lines, matches);
+                }
+            }
+            // Scrolling a region costs about as much as drawing a few cells
+            if best.0 != 0 && best.1 > unshifted + 4 {
+                let top = self.area.y + start as u16;
+                shifts.push((top..self.area.y + row as u16, best.0));
+            }
+        }
+        shifts
+    }
+
+    /// Moves the lines `rows` (in screen coordinates) up by `lines` lines (down when negative),
+    /// the lines scrolled into view being reset, as a terminal scrolling a region does.
+    pub(crate) fn scroll_rows(&mut self, rows: Range<u16>, lines: i32) {
+        let width = self.area.width as usize;
+        let start = (rows.start - self.area.y) as usize * width;
+        let end = (rows.end - self.area.y) as usize * width;
+        let region = &mut self.content[start..end];
+        let moved = min(lines.unsigned_abs() as usize * width, region.len());
+        let exposed = if lines > 0 {
+            region.rotate_left(moved);
+            let len = region.len();
+            &mut region[len - moved..]
+        } else {
+            region.rotate_right(moved);
+            &mut region[..moved]
+        };
+        for cell in exposed {
+            cell.reset();
+        }
+    }
 }
 
 #[cfg(test)]
@@ -607,6 +950,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn buffer_diffing_unchanged_rows() {
+        let prev = Buffer::with_lines(vec!["称号", "abcd", "ab称"]);
+        let next = Buffer::with_lines(vec!["称号", "abXd", "ab称"]);
+
+        assert_eq!(prev.diff(&next), vec![(2, 1, &cell("X"))]);
+        assert_eq!(next.diff(&next), vec![]);
+    }
+
+    #[test]
+    fn buffer_vertical_shifts_of_a_scrolled_pane() {
+        let prev = Buffer::with_lines(vec![
+            "┌──────┐side",
+            "│alpha │    ",
+            "│bravo │    ",
+            "│delta │ 42 ",
+            "│echo  │    ",
+            "└──────┘    ",
+        ]);
+        let next = Buffer::with_lines(vec![
+            "┌──────┐side",
+            "│bravo │    ",
+            "│delta │    ",
+            "│echo  │ 42 ",
+            "│golf  │    ",
+            "└──────┘    ",
+        ]);
+        assert_eq!(prev.vertical_shifts(&next), vec![(1..5, 1)]);
+        assert_eq!(next.vertical_shifts(&prev), vec![(1..5, -1)]);
+        assert_eq!(next.vertical_shifts(&next), vec![]);
+
+        // Once scrolled, the cells beside the pane and the new line are left to draw
+        let mut scrolled = prev.clone();
+        scrolled.scroll_rows(1..5, 1);
+        let updates: Vec<(u16, u16)> = scrolled
+            .diff(&next)
+            .into_iter()
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        assert_eq!(
+            updates,
+            vec![
+                (9, 2),
+                (10, 2),
+                (9, 3),
+                (10, 3),
+                (0, 4),
+                (1, 4),
+                (2, 4),
+                (3, 4),
+                (4, 4),
+                (7, 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn buffer_cells_store_long_graphemes() {
+        let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}";
+        let mut one = cell(family);
+        let two = one.clone();
+        assert_eq!(one.symbol(), family);
+        assert_eq!(one, two);
+        one.set_char('é');
+        assert_eq!(one.symbol(), "é");
+        assert_ne!(one, two);
+    }
+
+    #[test]
+    fn buffer_diffing_link_changes() {
+        let prev = Buffer::with_lines(vec!["ab"]);
+        let mut next = Buffer::with_lines(vec!["ab"]);
+        next.set_link(Rect::new(1, 0, 1, 1), Some("https://example.com"));
+
+        let mut linked = cell("b");
+        linked.set_link(Some(Arc::from("https://example.com")));
+        assert_eq!(prev.diff(&next), vec![(1, 0, &linked)]);
+    }
+
     #[test]
     fn buffer_merge() {
         let mut one = Buffer::filled(
@@ -0,0 +1,101 @@
+use crossterm::event;
+
+use super::{Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+impl From<event::KeyModifiers> for KeyModifiers {
+    fn from(modifiers: event::KeyModifiers) -> KeyModifiers {
+        let mut result = KeyModifiers::empty();
+        if modifiers.contains(event::KeyModifiers::SHIFT) {
+            result.insert(KeyModifiers::SHIFT);
+        }
+        if modifiers.contains(event::KeyModifiers::CONTROL) {
+            result.insert(KeyModifiers::CONTROL);
+        }
+        if modifiers.contains(event::KeyModifiers::ALT) {
+            result.insert(KeyModifiers::ALT);
+        }
+        result
+    }
+}
+
+impl From<event::KeyCode> for KeyCode {
+    fn from(code: event::KeyCode) -> KeyCode {
+        match code {
+            event::KeyCode::Backspace => KeyCode::Backspace,
+            event::KeyCode::Enter => KeyCode::Enter,
+            event::KeyCode::Left => KeyCode::Left,
+            event::KeyCode::Right => KeyCode::Right,
+            event::KeyCode::Up => KeyCode::Up,
+            event::KeyCode::Down => KeyCode::Down,
+            event::KeyCode::Home => KeyCode::Home,
+            event::KeyCode::End => KeyCode::End,
+            event::KeyCode::PageUp => KeyCode::PageUp,
+            event::KeyCode::PageDown => KeyCode::PageDown,
+            event::KeyCode::Tab => KeyCode::Tab,
+            event::KeyCode::BackTab => KeyCode::BackTab,
+            event::KeyCode::Delete => KeyCode::Delete,
+            event::KeyCode::Insert => KeyCode::Insert,
+            event::KeyCode::F(n) => KeyCode::F(n),
+            event::KeyCode::Char(c) => KeyCode::Char(c),
+            event::KeyCode::Null => KeyCode::Null,
+            event::KeyCode::Esc => KeyCode::Esc,
+        }
+    }
+}
+
+impl From<event::KeyEvent> for KeyEvent {
+    fn from(event: event::KeyEvent) -> KeyEvent {
+        KeyEvent {
+            code: event.code.into(),
+            modifiers: event.modifiers.into(),
+        }
+    }
+}
+
+impl From<event::MouseButton> for MouseButton {
+    fn from(button: event::MouseButton) -> MouseButton {
+        match button {
+            event::MouseButton::Left => MouseButton::Left,
+            event::MouseButton::Right => MouseButton::Right,
+            event::MouseButton::Middle => MouseButton::Middle,
+        }
+    }
+}
+
+impl From<event::MouseEvent> for MouseEvent {
+    fn from(event: event::MouseEvent) -> MouseEvent {
+        let (kind, column, row, modifiers) = match event {
+            event::MouseEvent::Down(button, x, y, modifiers) => {
+                (MouseEventKind::Down(button.into()), x, y, modifiers)
+            }
+            event::MouseEvent::Up(button, x, y, modifiers) => {
+                (MouseEventKind::Up(button.into()), x, y, modifiers)
+            }
+            event::MouseEvent::Drag(button, x, y, modifiers) => {
+                (MouseEventKind::Drag(button.into()), x, y, modifiers)
+            }
+            event::MouseEvent::ScrollDown(x, y, modifiers) => {
+                (MouseEventKind::ScrollDown, x, y, modifiers)
+            }
+            event::MouseEvent::ScrollUp(x, y, modifiers) => {
+                (MouseEventKind::ScrollUp, x, y, modifiers)
+            }
+        };
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: modifiers.into(),
+        }
+    }
+}
+
+impl From<event::Event> for Event {
+    fn from(event: event::Event) -> Event {
+        match event {
+            event::Event::Key(key) => Event::Key(key.into()),
+            event::Event::Mouse(mouse) => Event::Mouse(mouse.into()),
+            event::Event::Resize(width, height) => Event::Resize(width, height),
+        }
+    }
+}
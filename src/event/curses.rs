@@ -0,0 +1,138 @@
+use pancurses::Input;
+
+use super::{Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use crate::backend::CursesBackend;
+
+impl From<Input> for Event {
+    /// Converts keyboard input. `KeyResize` and `KeyMouse` carry no data on their own, they are
+    /// only converted by [`CursesBackend::read_event`].
+    fn from(input: Input) -> Event {
+        let (code, modifiers) = match input {
+            Input::Character('\n') | Input::Character('\r') | Input::KeyEnter => {
+                (KeyCode::Enter, KeyModifiers::empty())
+            }
+            Input::Character('\t') => (KeyCode::Tab, KeyModifiers::empty()),
+            Input::Character('\x1b') => (KeyCode::Esc, KeyModifiers::empty()),
+            Input::Character('\x7f') | Input::Character('\x08') | Input::KeyBackspace => {
+                (KeyCode::Backspace, KeyModifiers::empty())
+            }
+            // Control-a to Control-z are 0x01 to 0x1a, the other control bytes are the symbols
+            // following `@` in ASCII
+            Input::Character(c) if (c as u32) < 0x20 => (
+                KeyCode::Char((c as u8 + b'@').to_ascii_lowercase() as char),
+                KeyModifiers::CONTROL,
+            ),
+            Input::Character(c) => (KeyCode::Char(c), KeyModifiers::empty()),
+            Input::KeyLeft => (KeyCode::Left, KeyModifiers::empty()),
+            Input::KeyRight => (KeyCode::Right, KeyModifiers::empty()),
+            Input::KeyUp => (KeyCode::Up, KeyModifiers::empty()),
+            Input::KeyDown => (KeyCode::Down, KeyModifiers::empty()),
+            Input::KeyHome => (KeyCode::Home, KeyModifiers::empty()),
+            Input::KeyEnd => (KeyCode::End, KeyModifiers::empty()),
+            Input::KeyPPage => (KeyCode::PageUp, KeyModifiers::empty()),
+            Input::KeyNPage => (KeyCode::PageDown, KeyModifiers::empty()),
+            Input::KeyBTab => (KeyCode::BackTab, KeyModifiers::SHIFT),
+            Input::KeyDC => (KeyCode::Delete, KeyModifiers::empty()),
+            Input::KeyIC => (KeyCode::Insert, KeyModifiers::empty()),
+            Input::KeySLeft => (KeyCode::Left, KeyModifiers::SHIFT),
+            Input::KeySRight => (KeyCode::Right, KeyModifiers::SHIFT),
+            Input::KeySHome => (KeyCode::Home, KeyModifiers::SHIFT),
+            Input::KeySEnd => (KeyCode::End, KeyModifiers::SHIFT),
+            Input::KeySDC => (KeyCode::Delete, KeyModifiers::SHIFT),
+            Input::KeyF1 => (KeyCode::F(1), KeyModifiers::empty()),
+            Input::KeyF2 => (KeyCode::F(2), KeyModifiers::empty()),
+            Input::KeyF3 => (KeyCode::F(3), KeyModifiers::empty()),
+            Input::KeyF4 => (KeyCode::F(4), KeyModifiers::empty()),
+            Input::KeyF5 => (KeyCode::F(5), KeyModifiers::empty()),
+            Input::KeyF6 => (KeyCode::F(6), KeyModifiers::empty()),
+            Input::KeyF7 => (KeyCode::F(7), KeyModifiers::empty()),
+            Input::KeyF8 => (KeyCode::F(8), KeyModifiers::empty()),
+            Input::KeyF9 => (KeyCode::F(9), KeyModifiers::empty()),
+            Input::KeyF10 => (KeyCode::F(10), KeyModifiers::empty()),
+            Input::KeyF11 => (KeyCode::F(11), KeyModifiers::empty()),
+            Input::KeyF12 => (KeyCode::F(12), KeyModifiers::empty()),
+            _ => return Event::Unsupported,
+        };
+        Event::Key(KeyEvent { code, modifiers })
+    }
+}
+
+fn mouse_event(event: &pancurses::MEVENT) -> Option<MouseEvent> {
+    let bstate = event.bstate;
+    let kind = if bstate & (pancurses::BUTTON1_PRESSED | pancurses::BUTTON1_CLICKED) != 0 {
+        MouseEventKind::Down(MouseButton::Left)
+    } else if bstate & pancurses::BUTTON1_RELEASED != 0 {
+        MouseEventKind::Up(MouseButton::Left)
+    } else if bstate & (pancurses::BUTTON2_PRESSED | pancurses::BUTTON2_CLICKED) != 0 {
+        MouseEventKind::Down(MouseButton::Middle)
+    } else if bstate & pancurses::BUTTON2_RELEASED != 0 {
+        MouseEventKind::Up(MouseButton::Middle)
+    } else if bstate & (pancurses::BUTTON3_PRESSED | pancurses::BUTTON3_CLICKED) != 0 {
+        MouseEventKind::Down(MouseButton::Right)
+    } else if bstate & pancurses::BUTTON3_RELEASED != 0 {
+        MouseEventKind::Up(MouseButton::Right)
+    } else if bstate & pancurses::BUTTON4_PRESSED != 0 {
+        MouseEventKind::ScrollUp
+    } else if bstate & pancurses::BUTTON5_PRESSED != 0 {
+        MouseEventKind::ScrollDown
+    } else if bstate & pancurses::REPORT_MOUSE_POSITION != 0 {
+        // curses does not report which button is held, the left one is the most likely.
+        MouseEventKind::Drag(MouseButton::Left)
+    } else {
+        return None;
+    };
+    let mut modifiers = KeyModifiers::empty();
+    if bstate & pancurses::BUTTON_SHIFT != 0 {
+        modifiers.insert(KeyModifiers::SHIFT);
+    }
+    if bstate & pancurses::BUTTON_CTRL != 0 {
+        modifiers.insert(KeyModifiers::CONTROL);
+    }
+    if bstate & pancurses::BUTTON_ALT != 0 {
+        modifiers.insert(KeyModifiers::ALT);
+    }
+    Some(MouseEvent {
+        kind,
+        column: event.x.max(0) as u16,
+        row: event.y.max(0) as u16,
+        modifiers,
+    })
+}
+
+impl CursesBackend {
+    /// Reads the next input event using the input mode of the underlying curses window.
+    ///
+    /// Returns `None` if no input is available. Mouse events are only reported once they have
+    /// been requested with `pancurses::mousemask`.
+    pub fn read_event(&mut self) -> Option<Event> {
+        let curses = self.get_curses_mut();
+        let event = match curses.get_input()? {
+            Input::KeyResize => {
+                let (rows, cols) = curses.get_row_col_count();
+                Event::Resize(cols as u16, rows as u16)
+            }
+            Input::KeyMouse => pancurses::getmouse()
+                .ok()
+                .and_then(|event| mouse_event(&event))
+                .map_or(Event::Unsupported, Event::Mouse),
+            input => Event::from(input),
+        };
+        Some(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_from_curses_control_characters() {
+        let control = |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL));
+        assert_eq!(Event::from(Input::Character('\x01')), control('a'));
+        assert_eq!(Event::from(Input::Character('\x1a')), control('z'));
+        assert_eq!(Event::from(Input::Character('\x1c')), control('\\'));
+        assert_eq!(Event::from(Input::Character('\x1d')), control(']'));
+        assert_eq!(Event::from(Input::Character('\x1e')), control('^'));
+        assert_eq!(Event::from(Input::Character('\x1f')), control('_'));
+    }
+}
@@ -0,0 +1,128 @@
+//! `event` provides a backend-agnostic representation of terminal input.
+//!
+//! Each backend reads input with its own types. This module defines a common [`Event`] type
+//! along with conversions from the events of every supported backend, so that application code
+//! and widget handlers only have to be written once:
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "termion")]
+//! # fn main() -> Result<(), std::io::Error> {
+//! use std::io;
+//! use termion::input::TermRead;
+//! use tui::event::{Event, KeyCode};
+//!
+//! for evt in io::stdin().events() {
+//!     match Event::from(evt?) {
+//!         Event::Key(key) if key.code == KeyCode::Char('q') => break,
+//!         Event::Key(key) => println!("{:?}", key),
+//!         _ => {}
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "termion"))]
+//! # fn main() {}
+//! ```
+//!
+//! Coordinates of mouse events are always 0-based, whatever the convention of the backend.
+
+use bitflags::bitflags;
+
+#[cfg(feature = "termion")]
+mod termion;
+
+#[cfg(feature = "crossterm")]
+mod crossterm;
+
+#[cfg(feature = "rustbox")]
+mod rustbox;
+
+#[cfg(feature = "curses")]
+mod curses;
+
+/// An input event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event {
+    Key(KeyEvent),
+    Mouse(MouseEvent),
+    /// The terminal was resized. Holds the new width and height.
+    Resize(u16, u16),
+    /// An event the backend reported but that has no equivalent here.
+    Unsupported,
+}
+
+bitflags! {
+    /// Modifier keys held down while a key was pressed or a mouse event occurred.
+    pub struct KeyModifiers: u8 {
+        const SHIFT   = 0b0000_0001;
+        const CONTROL = 0b0000_0010;
+        const ALT     = 0b0000_0100;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyCode {
+    Backspace,
+    Enter,
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Tab,
+    BackTab,
+    Delete,
+    Insert,
+    /// Function key, `F(1)` being F1.
+    F(u8),
+    Char(char),
+    Null,
+    Esc,
+}
+
+/// A key press along with the modifiers held down at that time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyEvent {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyEvent {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent { code, modifiers }
+    }
+}
+
+impl From<KeyCode> for KeyEvent {
+    fn from(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseEventKind {
+    Down(MouseButton),
+    Up(MouseButton),
+    Drag(MouseButton),
+    ScrollDown,
+    ScrollUp,
+}
+
+/// A mouse event at the given 0-based terminal position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MouseEvent {
+    pub kind: MouseEventKind,
+    pub column: u16,
+    pub row: u16,
+    pub modifiers: KeyModifiers,
+}
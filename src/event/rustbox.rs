@@ -0,0 +1,59 @@
+use rustbox::{self, Key, Mouse};
+
+use super::{Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+impl From<Key> for KeyEvent {
+    fn from(key: Key) -> KeyEvent {
+        let (code, modifiers) = match key {
+            Key::Tab => (KeyCode::Tab, KeyModifiers::empty()),
+            Key::Enter => (KeyCode::Enter, KeyModifiers::empty()),
+            Key::Esc => (KeyCode::Esc, KeyModifiers::empty()),
+            Key::Backspace => (KeyCode::Backspace, KeyModifiers::empty()),
+            Key::Right => (KeyCode::Right, KeyModifiers::empty()),
+            Key::Left => (KeyCode::Left, KeyModifiers::empty()),
+            Key::Up => (KeyCode::Up, KeyModifiers::empty()),
+            Key::Down => (KeyCode::Down, KeyModifiers::empty()),
+            Key::Delete => (KeyCode::Delete, KeyModifiers::empty()),
+            Key::Insert => (KeyCode::Insert, KeyModifiers::empty()),
+            Key::Home => (KeyCode::Home, KeyModifiers::empty()),
+            Key::End => (KeyCode::End, KeyModifiers::empty()),
+            Key::PageUp => (KeyCode::PageUp, KeyModifiers::empty()),
+            Key::PageDown => (KeyCode::PageDown, KeyModifiers::empty()),
+            Key::Char(c) => (KeyCode::Char(c), KeyModifiers::empty()),
+            Key::Ctrl(c) => (KeyCode::Char(c), KeyModifiers::CONTROL),
+            Key::F(n) => (KeyCode::F(n as u8), KeyModifiers::empty()),
+            Key::Unknown(_) => (KeyCode::Null, KeyModifiers::empty()),
+        };
+        KeyEvent { code, modifiers }
+    }
+}
+
+impl From<rustbox::Event> for Event {
+    fn from(event: rustbox::Event) -> Event {
+        match event {
+            rustbox::Event::KeyEvent(key) => Event::Key(key.into()),
+            rustbox::Event::ResizeEvent(width, height) => {
+                Event::Resize(width as u16, height as u16)
+            }
+            rustbox::Event::MouseEvent(mouse, x, y) => {
+                // rustbox does not report which button was released, the left one is the most
+                // likely.
+                let kind = match mouse {
+                    Mouse::Left => MouseEventKind::Down(MouseButton::Left),
+                    Mouse::Right => MouseEventKind::Down(MouseButton::Right),
+                    Mouse::Middle => MouseEventKind::Down(MouseButton::Middle),
+                    Mouse::Release => MouseEventKind::Up(MouseButton::Left),
+                    Mouse::WheelUp => MouseEventKind::ScrollUp,
+                    Mouse::WheelDown => MouseEventKind::ScrollDown,
+                };
+                Event::Mouse(MouseEvent {
+                    kind,
+                    column: x as u16,
+                    row: y as u16,
+                    modifiers: KeyModifiers::empty(),
+                })
+            }
+            _ => Event::Unsupported,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use termion::event::{self, Key};
+
+use super::{Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+impl From<Key> for KeyEvent {
+    fn from(key: Key) -> KeyEvent {
+        let (code, modifiers) = match key {
+            Key::Backspace => (KeyCode::Backspace, KeyModifiers::empty()),
+            Key::Left => (KeyCode::Left, KeyModifiers::empty()),
+            Key::Right => (KeyCode::Right, KeyModifiers::empty()),
+            Key::Up => (KeyCode::Up, KeyModifiers::empty()),
+            Key::Down => (KeyCode::Down, KeyModifiers::empty()),
+            Key::Home => (KeyCode::Home, KeyModifiers::empty()),
+            Key::End => (KeyCode::End, KeyModifiers::empty()),
+            Key::PageUp => (KeyCode::PageUp, KeyModifiers::empty()),
+            Key::PageDown => (KeyCode::PageDown, KeyModifiers::empty()),
+            Key::BackTab => (KeyCode::BackTab, KeyModifiers::SHIFT),
+            Key::Delete => (KeyCode::Delete, KeyModifiers::empty()),
+            Key::Insert => (KeyCode::Insert, KeyModifiers::empty()),
+            Key::F(n) => (KeyCode::F(n), KeyModifiers::empty()),
+            // termion does not have dedicated variants for these two keys
+            Key::Char('\n') => (KeyCode::Enter, KeyModifiers::empty()),
+            Key::Char('\t') => (KeyCode::Tab, KeyModifiers::empty()),
+            Key::Char(c) => (KeyCode::Char(c), KeyModifiers::empty()),
+            Key::Alt(c) => (KeyCode::Char(c), KeyModifiers::ALT),
+            Key::Ctrl(c) => (KeyCode::Char(c), KeyModifiers::CONTROL),
+            Key::Esc => (KeyCode::Esc, KeyModifiers::empty()),
+            _ => (KeyCode::Null, KeyModifiers::empty()),
+        };
+        KeyEvent { code, modifiers }
+    }
+}
+
+impl From<event::MouseEvent> for MouseEvent {
+    fn from(event: event::MouseEvent) -> MouseEvent {
+        // termion does not report which button was released or held, the left one is the most
+        // likely.
+        let (kind, x, y) = match event {
+            event::MouseEvent::Press(button, x, y) => {
+                let kind = match button {
+                    event::MouseButton::Left => MouseEventKind::Down(MouseButton::Left),
+                    event::MouseButton::Right => MouseEventKind::Down(MouseButton::Right),
+                    event::MouseButton::Middle => MouseEventKind::Down(MouseButton::Middle),
+                    event::MouseButton::WheelUp => MouseEventKind::ScrollUp,
+                    event::MouseButton::WheelDown => MouseEventKind::ScrollDown,
+                };
+                (kind, x, y)
+            }
+            event::MouseEvent::Release(x, y) => (MouseEventKind::Up(MouseButton::Left), x, y),
+            event::MouseEvent::Hold(x, y) => (MouseEventKind::Drag(MouseButton::Left), x, y),
+        };
+        MouseEvent {
+            kind,
+            column: x.saturating_sub(1),
+            row: y.saturating_sub(1),
+            modifiers: KeyModifiers::empty(),
+        }
+    }
+}
+
+impl From<event::Event> for Event {
+    fn from(event: event::Event) -> Event {
+        match event {
+            event::Event::Key(key) => Event::Key(key.into()),
+            event::Event::Mouse(mouse) => Event::Mouse(mouse.into()),
+            event::Event::Unsupported(_) => Event::Unsupported,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_from_termion_key() {
+        assert_eq!(
+            KeyEvent::from(Key::Char('\n')),
+            KeyEvent::from(KeyCode::Enter)
+        );
+        assert_eq!(
+            KeyEvent::from(Key::Ctrl('c')),
+            KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            KeyEvent::from(Key::BackTab),
+            KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT)
+        );
+    }
+
+    #[test]
+    fn event_from_termion_mouse_is_zero_based() {
+        let event = Event::from(event::Event::Mouse(event::MouseEvent::Press(
+            event::MouseButton::Left,
+            1,
+            3,
+        )));
+        assert_eq!(
+            event,
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column: 0,
+                row: 2,
+                modifiers: KeyModifiers::empty(),
+            })
+        );
+    }
+}
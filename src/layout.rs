@@ -1,6 +1,9 @@
 use std::cell::RefCell;
 use std::cmp::{max, min};
 use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::str::FromStr;
 
 use cassowary::strength::{REQUIRED, WEAK};
 use cassowary::WeightedRelation::*;
@@ -45,6 +48,66 @@ impl Constraint {
     }
 }
 
+/// Parses a constraint written as `"50%"` (percentage), `"1/3"` (ratio), `"10"` (length),
+/// `"min:10"` or `"max:10"`.
+///
+/// ```rust
+/// # use tui::layout::Constraint;
+/// assert_eq!("50%".parse(), Ok(Constraint::Percentage(50)));
+/// assert_eq!("min:10".parse(), Ok(Constraint::Min(10)));
+/// assert_eq!("1/3".parse(), Ok(Constraint::Ratio(1, 3)));
+/// assert_eq!(Constraint::Max(4).to_string(), "max:4");
+/// ```
+impl FromStr for Constraint {
+    type Err = ParseConstraintError;
+
+    fn from_str(s: &str) -> Result<Constraint, ParseConstraintError> {
+        let s = s.trim();
+        let error = || ParseConstraintError(s.to_owned());
+        let number = |n: &str| n.trim().parse().map_err(|_| error());
+        let constraint = if let Some(p) = s.strip_suffix('%') {
+            Constraint::Percentage(number(p)?)
+        } else if let Some(m) = s.strip_prefix("min:") {
+            Constraint::Min(number(m)?)
+        } else if let Some(m) = s.strip_prefix("max:") {
+            Constraint::Max(number(m)?)
+        } else if let Some(slash) = s.find('/') {
+            let den = s[slash + 1..].trim().parse().map_err(|_| error())?;
+            if den == 0 {
+                return Err(error());
+            }
+            Constraint::Ratio(s[..slash].trim().parse().map_err(|_| error())?, den)
+        } else {
+            Constraint::Length(number(s)?)
+        };
+        Ok(constraint)
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Constraint::Percentage(p) => write!(f, "{}%", p),
+            Constraint::Ratio(num, den) => write!(f, "{}/{}", num, den),
+            Constraint::Length(l) => write!(f, "{}", l),
+            Constraint::Max(m) => write!(f, "max:{}", m),
+            Constraint::Min(m) => write!(f, "min:{}", m),
+        }
+    }
+}
+
+/// Error returned when a constraint cannot be parsed, holding the invalid input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseConstraintError(pub String);
+
+impl fmt::Display for ParseConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid constraint `{}`", self.0)
+    }
+}
+
+impl error::Error for ParseConstraintError {}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Margin {
     pub vertical: u16,
@@ -482,6 +545,11 @@ impl Rect {
             && self.y < other.y + other.height
             && self.y + self.height > other.y
     }
+
+    /// Returns true if the given position lies within the rect.
+    pub fn contains(self, x: u16, y: u16) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
 }
 
 #[cfg(test)]
@@ -25,6 +25,12 @@
 //!
 //! The same logic applies for all other available backends.
 //!
+//! The optional `serde` feature implements `Serialize` and `Deserialize` for
+//! [`Color`](style::Color), [`Modifier`](style::Modifier), [`Style`](style::Style) and
+//! [`Constraint`](layout::Constraint), using the same strings as their `FromStr` implementations
+//! (`"light_blue"`, `"bold|italic"`, `"fg=red;mod=bold"`, `"min:10"`...), so that they can be read
+//! from configuration files.
+//!
 //! ## Creating a `Terminal`
 //!
 //! Every application using `tui` should start by instantiating a `Terminal`. It is a light
@@ -94,7 +100,8 @@
 //!             .title("Block")
 //!             .borders(Borders::ALL);
 //!         f.render_widget(block, size);
-//!     })
+//!     })?;
+//!     Ok(())
 //! }
 //! ```
 //!
@@ -136,7 +143,8 @@
 //!              .title("Block 2")
 //!              .borders(Borders::ALL);
 //!         f.render_widget(block, chunks[1]);
-//!     })
+//!     })?;
+//!     Ok(())
 //! }
 //! ```
 //!
@@ -147,10 +155,15 @@
 
 pub mod backend;
 pub mod buffer;
+pub mod event;
 pub mod layout;
+#[cfg(feature = "serde")]
+mod serialization;
 pub mod style;
 pub mod symbols;
 pub mod terminal;
+pub mod text;
+pub mod theme;
 pub mod widgets;
 
-pub use self::terminal::{Frame, Terminal};
+pub use self::terminal::{CompletedFrame, Frame, Hit, HitMap, Terminal, TerminalOptions, Viewport};
@@ -0,0 +1,80 @@
+//! Serialization of the styles and constraints as the strings parsed by their `FromStr`
+//! implementations.
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::layout::Constraint;
+use crate::style::{Color, Modifier, Style};
+
+fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Display,
+    S: Serializer,
+{
+    serializer.collect_str(value)
+}
+
+fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    let s = Cow::<str>::deserialize(deserializer)?;
+    s.parse().map_err(de::Error::custom)
+}
+
+macro_rules! impl_serde_from_str {
+    ($($ty:ty),*) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize(self, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$ty, D::Error> {
+                    deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_from_str!(Color, Modifier, Style, Constraint);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::value::{Error, StrDeserializer};
+    use serde::de::IntoDeserializer;
+
+    fn from_str<'de, T: Deserialize<'de>>(s: &'de str) -> Result<T, Error> {
+        let deserializer: StrDeserializer<Error> = s.into_deserializer();
+        T::deserialize(deserializer)
+    }
+
+    #[test]
+    fn serialization_deserialize_from_strings() {
+        assert_eq!(from_str("light_blue"), Ok(Color::LightBlue));
+        assert_eq!(from_str("#ff8800"), Ok(Color::Rgb(255, 136, 0)));
+        assert_eq!(
+            from_str("bold|italic"),
+            Ok(Modifier::BOLD | Modifier::ITALIC)
+        );
+        assert_eq!(
+            from_str("fg=red;bg=123"),
+            Ok(Style::default().fg(Color::Red).bg(Color::Indexed(123)))
+        );
+        assert_eq!(from_str("1/3"), Ok(Constraint::Ratio(1, 3)));
+        assert_eq!(
+            from_str::<Constraint>("min:x").unwrap_err().to_string(),
+            "invalid constraint `min:x`"
+        );
+    }
+}
@@ -1,6 +1,11 @@
+use std::env;
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
 use bitflags::bitflags;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
 pub enum Color {
     Reset,
     Black,
@@ -23,27 +28,437 @@ pub enum Color {
     Indexed(u8),
 }
 
+/// Names of the colors, as parsed and displayed.
+const COLOR_NAMES: [(Color, &str); 17] = [
+    (Color::Reset, "reset"),
+    (Color::Black, "black"),
+    (Color::Red, "red"),
+    (Color::Green, "green"),
+    (Color::Yellow, "yellow"),
+    (Color::Blue, "blue"),
+    (Color::Magenta, "magenta"),
+    (Color::Cyan, "cyan"),
+    (Color::Gray, "gray"),
+    (Color::DarkGray, "darkgray"),
+    (Color::LightRed, "lightred"),
+    (Color::LightGreen, "lightgreen"),
+    (Color::LightYellow, "lightyellow"),
+    (Color::LightBlue, "lightblue"),
+    (Color::LightMagenta, "lightmagenta"),
+    (Color::LightCyan, "lightcyan"),
+    (Color::White, "white"),
+];
+
+/// Lowercases a name and removes its `_` and `-` separators, so that `light_blue`, `LightBlue`
+/// and `lightblue` are the same name.
+fn normalize_name(s: &str) -> String {
+    s.chars()
+        .filter(|&c| c != '_' && c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Parses a color name (`"lightblue"`, `"light_blue"`...), a truecolor in hexadecimal
+/// (`"#ff8800"`) or an index in the 256 colors palette (`"123"`).
+///
+/// ```rust
+/// # use tui::style::Color;
+/// assert_eq!("light_blue".parse(), Ok(Color::LightBlue));
+/// assert_eq!("#ff8800".parse(), Ok(Color::Rgb(255, 136, 0)));
+/// assert_eq!("123".parse(), Ok(Color::Indexed(123)));
+/// assert_eq!(Color::Rgb(255, 136, 0).to_string(), "#ff8800");
+/// ```
+impl FromStr for Color {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Color, ParseStyleError> {
+        let s = s.trim();
+        let error = || ParseStyleError::InvalidColor(s.to_owned());
+        if s.starts_with('#') {
+            if s.len() != 7 {
+                return Err(error());
+            }
+            let component = |i| {
+                s.get(i..i + 2)
+                    .and_then(|c| u8::from_str_radix(c, 16).ok())
+                    .ok_or_else(error)
+            };
+            return Ok(Color::Rgb(component(1)?, component(3)?, component(5)?));
+        }
+        if let Ok(index) = s.parse::<u8>() {
+            return Ok(Color::Indexed(index));
+        }
+        let name = normalize_name(s);
+        COLOR_NAMES
+            .iter()
+            .find(|&&(_, n)| n == name)
+            .map(|&(color, _)| color)
+            .ok_or_else(error)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Color::Rgb(r, g, b) => write!(f, "#{:02x}{:02x}{:02x}", r, g, b),
+            Color::Indexed(i) => write!(f, "{}", i),
+            color => {
+                let name = COLOR_NAMES
+                    .iter()
+                    .find(|&&(c, _)| c == color)
+                    .map_or("", |&(_, n)| n);
+                f.write_str(name)
+            }
+        }
+    }
+}
+
+/// RGB values of the 16 ANSI colors, as displayed by xterm.
+const ANSI_16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Intensities of each component in the 6x6x6 color cube of the 256 colors palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl Color {
+    /// Converts the color to the closest one a terminal with the given color depth can display.
+    ///
+    /// ```rust
+    /// # use tui::style::{Color, ColorDepth};
+    /// assert_eq!(Color::Rgb(255, 135, 0).downsample(ColorDepth::Ansi256), Color::Indexed(208));
+    /// assert_eq!(Color::Rgb(255, 135, 0).downsample(ColorDepth::Ansi16), Color::Yellow);
+    /// assert_eq!(Color::Indexed(9).downsample(ColorDepth::Ansi16), Color::LightRed);
+    /// ```
+    pub fn downsample(self, depth: ColorDepth) -> Color {
+        match (self, depth) {
+            (Color::Rgb(r, g, b), ColorDepth::Ansi256) => Color::Indexed(rgb_to_indexed(r, g, b)),
+            (Color::Rgb(r, g, b), ColorDepth::Ansi16) => rgb_to_ansi16((r, g, b)),
+            (Color::Indexed(i), ColorDepth::Ansi16) if i < 16 => ANSI_16[i as usize].0,
+            (Color::Indexed(i), ColorDepth::Ansi16) => rgb_to_ansi16(indexed_to_rgb(i)),
+            (color, _) => color,
+        }
+    }
+}
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+fn rgb_to_ansi16(rgb: (u8, u8, u8)) -> Color {
+    ANSI_16
+        .iter()
+        .min_by_key(|&&(_, c)| distance(rgb, c))
+        .map(|&(color, _)| color)
+        .unwrap_or(Color::Reset)
+}
+
+/// Closest color of the 256 colors palette, ignoring the 16 first ones that terminals tend to
+/// customize.
+fn rgb_to_indexed(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| (i32::from(CUBE_LEVELS[i]) - i32::from(c)).abs())
+            .unwrap_or(0) as u8
+    };
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+    // The gray ramp goes from 8 to 238 by steps of 10
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray = 232 + ((average.max(3) - 3) / 10).min(23) as u8;
+    if distance((r, g, b), indexed_to_rgb(gray)) < distance((r, g, b), indexed_to_rgb(cube)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+fn indexed_to_rgb(i: u8) -> (u8, u8, u8) {
+    match i {
+        0..=15 => ANSI_16[i as usize].1,
+        16..=231 => {
+            let i = i - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let gray = 8 + (i - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Number of colors a terminal can display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorDepth {
+    /// The 16 ANSI colors
+    Ansi16,
+    /// The 256 colors palette
+    Ansi256,
+    /// Any RGB color
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Guesses the color depth of the terminal from the `COLORTERM` and `TERM` environment
+    /// variables.
+    pub fn detect() -> ColorDepth {
+        let colorterm = env::var("COLORTERM").ok();
+        let term = env::var("TERM").ok();
+        ColorDepth::from_env(colorterm.as_deref(), term.as_deref())
+    }
+
+    /// Guesses the color depth of the terminal from the values of the `COLORTERM` and `TERM`
+    /// environment variables.
+    ///
+    /// Colors are only downsampled for the terminals known to have a limited palette, such as the
+    /// Linux console, `vt100`, `dumb` and the `*-16color` terminals. A missing or unknown `TERM`
+    /// gets the true colors.
+    ///
+    /// ```rust
+    /// # use tui::style::ColorDepth;
+    /// assert_eq!(ColorDepth::from_env(Some("truecolor"), Some("xterm")), ColorDepth::TrueColor);
+    /// assert_eq!(ColorDepth::from_env(None, Some("screen-256color")), ColorDepth::Ansi256);
+    /// assert_eq!(ColorDepth::from_env(None, Some("linux")), ColorDepth::Ansi16);
+    /// assert_eq!(ColorDepth::from_env(None, Some("rxvt-16color")), ColorDepth::Ansi16);
+    /// assert_eq!(ColorDepth::from_env(None, Some("some-new-term")), ColorDepth::TrueColor);
+    /// assert_eq!(ColorDepth::from_env(None, None), ColorDepth::TrueColor);
+    /// ```
+    pub fn from_env(colorterm: Option<&str>, term: Option<&str>) -> ColorDepth {
+        if let Some("truecolor") | Some("24bit") = colorterm {
+            return ColorDepth::TrueColor;
+        }
+        match term {
+            Some("linux") | Some("vt100") | Some("dumb") => ColorDepth::Ansi16,
+            Some(term) if term.ends_with("-16color") => ColorDepth::Ansi16,
+            Some(term) if term.contains("256") => ColorDepth::Ansi256,
+            _ => ColorDepth::TrueColor,
+        }
+    }
+}
+
 bitflags! {
+    /// Modifiers changing the way text is drawn.
+    ///
+    /// `DOUBLE_UNDERLINED`, `UNDERCURLED`, `UNDERDOTTED` and `UNDERDASHED` are drawn by the
+    /// termion and crossterm backends with the extended underline sequences of modern terminals,
+    /// the other backends draw a plain underline instead. When several underlines are set, the
+    /// most specific one is drawn.
     pub struct Modifier: u16 {
-        const BOLD              = 0b0000_0000_0001;
-        const DIM               = 0b0000_0000_0010;
-        const ITALIC            = 0b0000_0000_0100;
-        const UNDERLINED        = 0b0000_0000_1000;
-        const SLOW_BLINK        = 0b0000_0001_0000;
-        const RAPID_BLINK       = 0b0000_0010_0000;
-        const REVERSED          = 0b0000_0100_0000;
-        const HIDDEN            = 0b0000_1000_0000;
-        const CROSSED_OUT       = 0b0001_0000_0000;
+        const BOLD              = 0b0000_0000_0000_0001;
+        const DIM               = 0b0000_0000_0000_0010;
+        const ITALIC            = 0b0000_0000_0000_0100;
+        const UNDERLINED        = 0b0000_0000_0000_1000;
+        const SLOW_BLINK        = 0b0000_0000_0001_0000;
+        const RAPID_BLINK       = 0b0000_0000_0010_0000;
+        const REVERSED          = 0b0000_0000_0100_0000;
+        const HIDDEN            = 0b0000_0000_1000_0000;
+        const CROSSED_OUT       = 0b0000_0001_0000_0000;
+        const DOUBLE_UNDERLINED = 0b0000_0010_0000_0000;
+        const UNDERCURLED       = 0b0000_0100_0000_0000;
+        const UNDERDOTTED       = 0b0000_1000_0000_0000;
+        const UNDERDASHED       = 0b0001_0000_0000_0000;
+        /// All the styles of underline
+        const ALL_UNDERLINES    = Self::UNDERLINED.bits
+            | Self::DOUBLE_UNDERLINED.bits
+            | Self::UNDERCURLED.bits
+            | Self::UNDERDOTTED.bits
+            | Self::UNDERDASHED.bits;
     }
 }
 
+/// Names of the modifiers, as parsed and displayed.
+const MODIFIER_NAMES: [(Modifier, &str); 13] = [
+    (Modifier::BOLD, "bold"),
+    (Modifier::DIM, "dim"),
+    (Modifier::ITALIC, "italic"),
+    (Modifier::UNDERLINED, "underlined"),
+    (Modifier::SLOW_BLINK, "slow_blink"),
+    (Modifier::RAPID_BLINK, "rapid_blink"),
+    (Modifier::REVERSED, "reversed"),
+    (Modifier::HIDDEN, "hidden"),
+    (Modifier::CROSSED_OUT, "crossed_out"),
+    (Modifier::DOUBLE_UNDERLINED, "double_underlined"),
+    (Modifier::UNDERCURLED, "undercurled"),
+    (Modifier::UNDERDOTTED, "underdotted"),
+    (Modifier::UNDERDASHED, "underdashed"),
+];
+
+/// Parses a list of modifiers separated by `|`, such as `"bold|italic"`. An empty string is no
+/// modifier.
+///
+/// ```rust
+/// # use tui::style::Modifier;
+/// assert_eq!("bold|slow_blink".parse(), Ok(Modifier::BOLD | Modifier::SLOW_BLINK));
+/// assert_eq!((Modifier::ITALIC | Modifier::DIM).to_string(), "dim|italic");
+/// ```
+impl FromStr for Modifier {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Modifier, ParseStyleError> {
+        let mut modifier = Modifier::empty();
+        if s.trim().is_empty() {
+            return Ok(modifier);
+        }
+        for name in s.split('|') {
+            let normalized = normalize_name(name);
+            let &(m, _) = MODIFIER_NAMES
+                .iter()
+                .find(|&&(_, n)| normalize_name(n) == normalized)
+                .ok_or_else(|| ParseStyleError::InvalidModifier(name.trim().to_owned()))?;
+            modifier.insert(m);
+        }
+        Ok(modifier)
+    }
+}
+
+impl fmt::Display for Modifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut names = MODIFIER_NAMES
+            .iter()
+            .filter(|&&(m, _)| self.contains(m))
+            .map(|&(_, n)| n);
+        if let Some(name) = names.next() {
+            f.write_str(name)?;
+        }
+        for name in names {
+            write!(f, "|{}", name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Style to apply on top of the current style of some cells.
+///
+/// Colors left to `None` and modifiers neither added nor removed keep their current value, so
+/// that styles can be layered with [`Style::patch`]: a widget draws its items with its own style
+/// patched by the style of the item, and a highlight only changes what it sets.
+///
+/// ```rust
+/// # use tui::style::{Color, Modifier, Style};
+/// let item = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+/// let highlight = Style::default()
+///     .add_modifier(Modifier::REVERSED)
+///     .remove_modifier(Modifier::BOLD);
+/// assert_eq!(
+///     item.patch(highlight),
+///     Style::default()
+///         .fg(Color::Red)
+///         .add_modifier(Modifier::REVERSED)
+///         .remove_modifier(Modifier::BOLD)
+/// );
+/// ```
+///
+/// Styles can be built in constants, starting from [`Style::new`]:
+///
+/// ```rust
+/// # use tui::style::{Color, Modifier, Style};
+/// const WARNING: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Style {
-    pub fg: Color,
-    pub bg: Color,
-    pub modifier: Modifier,
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    /// Color of the underlines, which are drawn with the foreground color when it is `Reset`
+    pub underline_color: Option<Color>,
+    pub add_modifier: Modifier,
+    pub sub_modifier: Modifier,
+}
+
+/// Parses `key=value` attributes separated by `;`: `fg`, `bg` and `ul` set the foreground,
+/// background and underline colors, `mod` adds some modifiers and `remove` removes some. An empty
+/// string is the default style.
+///
+/// ```rust
+/// # use tui::style::{Color, Modifier, Style};
+/// let style = Style::default()
+///     .fg(Color::Red)
+///     .add_modifier(Modifier::BOLD | Modifier::ITALIC)
+///     .remove_modifier(Modifier::DIM);
+/// assert_eq!("fg=red;mod=bold|italic;remove=dim".parse(), Ok(style));
+/// assert_eq!(style.to_string(), "fg=red;mod=bold|italic;remove=dim");
+/// ```
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Style, ParseStyleError> {
+        let mut style = Style::default();
+        for attribute in s.split(';').filter(|a| !a.trim().is_empty()) {
+            let mut parts = attribute.splitn(2, '=').map(str::trim);
+            match (parts.next(), parts.next()) {
+                (Some("fg"), Some(value)) => style = style.fg(value.parse()?),
+                (Some("bg"), Some(value)) => style = style.bg(value.parse()?),
+                (Some("ul"), Some(value)) => style = style.underline_color(value.parse()?),
+                (Some("mod"), Some(value)) => style = style.add_modifier(value.parse()?),
+                (Some("remove"), Some(value)) => style = style.remove_modifier(value.parse()?),
+                _ => return Err(ParseStyleError::InvalidAttribute(attribute.to_owned())),
+            }
+        }
+        Ok(style)
+    }
+}
+
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut attributes = Vec::new();
+        if let Some(fg) = self.fg {
+            attributes.push(format!("fg={}", fg));
+        }
+        if let Some(bg) = self.bg {
+            attributes.push(format!("bg={}", bg));
+        }
+        if let Some(ul) = self.underline_color {
+            attributes.push(format!("ul={}", ul));
+        }
+        if !self.add_modifier.is_empty() {
+            attributes.push(format!("mod={}", self.add_modifier));
+        }
+        if !self.sub_modifier.is_empty() {
+            attributes.push(format!("remove={}", self.sub_modifier));
+        }
+        f.write_str(&attributes.join(";"))
+    }
+}
+
+/// Error returned when a color, some modifiers or a style cannot be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseStyleError {
+    InvalidColor(String),
+    InvalidModifier(String),
+    /// An attribute of a style is not a `key=value` pair with a known key
+    InvalidAttribute(String),
 }
 
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseStyleError::InvalidColor(ref c) => write!(f, "invalid color `{}`", c),
+            ParseStyleError::InvalidModifier(ref m) => write!(f, "invalid modifier `{}`", m),
+            ParseStyleError::InvalidAttribute(ref a) => write!(f, "invalid attribute `{}`", a),
+        }
+    }
+}
+
+impl error::Error for ParseStyleError {}
+
 impl Default for Style {
     fn default() -> Style {
         Style::new()
@@ -51,29 +466,64 @@ impl Default for Style {
 }
 
 impl Style {
+    /// A style changing nothing.
     pub const fn new() -> Self {
         Style {
-            fg: Color::Reset,
-            bg: Color::Reset,
-            modifier: Modifier::empty(),
+            fg: None,
+            bg: None,
+            underline_color: None,
+            add_modifier: Modifier::empty(),
+            sub_modifier: Modifier::empty(),
         }
     }
-    pub fn reset(&mut self) {
-        self.fg = Color::Reset;
-        self.bg = Color::Reset;
-        self.modifier = Modifier::empty();
+
+    /// A style resetting the colors and removing all the modifiers.
+    pub fn reset() -> Style {
+        Style {
+            fg: Some(Color::Reset),
+            bg: Some(Color::Reset),
+            underline_color: Some(Color::Reset),
+            add_modifier: Modifier::empty(),
+            sub_modifier: Modifier::all(),
+        }
     }
 
     pub const fn fg(mut self, color: Color) -> Style {
-        self.fg = color;
+        self.fg = Some(color);
         self
     }
     pub const fn bg(mut self, color: Color) -> Style {
-        self.bg = color;
+        self.bg = Some(color);
+        self
+    }
+    pub const fn underline_color(mut self, color: Color) -> Style {
+        self.underline_color = Some(color);
         self
     }
-    pub const fn modifier(mut self, modifier: Modifier) -> Style {
-        self.modifier = modifier;
+    pub const fn add_modifier(mut self, modifier: Modifier) -> Style {
+        self.sub_modifier =
+            Modifier::from_bits_truncate(self.sub_modifier.bits() & !modifier.bits());
+        self.add_modifier =
+            Modifier::from_bits_truncate(self.add_modifier.bits() | modifier.bits());
+        self
+    }
+    pub const fn remove_modifier(mut self, modifier: Modifier) -> Style {
+        self.add_modifier =
+            Modifier::from_bits_truncate(self.add_modifier.bits() & !modifier.bits());
+        self.sub_modifier =
+            Modifier::from_bits_truncate(self.sub_modifier.bits() | modifier.bits());
+        self
+    }
+
+    /// Returns this style with the colors and modifiers set by `other` applied on top of it.
+    pub fn patch(mut self, other: Style) -> Style {
+        self.fg = other.fg.or(self.fg);
+        self.bg = other.bg.or(self.bg);
+        self.underline_color = other.underline_color.or(self.underline_color);
+        self.add_modifier.remove(other.sub_modifier);
+        self.add_modifier.insert(other.add_modifier);
+        self.sub_modifier.remove(other.add_modifier);
+        self.sub_modifier.insert(other.sub_modifier);
         self
     }
 }
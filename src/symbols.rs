@@ -209,6 +209,41 @@ pub mod line {
     };
 }
 
+pub mod scrollbar {
+    use super::{block, line};
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct Set {
+        pub track: &'static str,
+        pub thumb: &'static str,
+    }
+
+    pub const VERTICAL: Set = Set {
+        track: line::VERTICAL,
+        thumb: block::FULL,
+    };
+
+    pub const HORIZONTAL: Set = Set {
+        track: line::HORIZONTAL,
+        thumb: block::FULL,
+    };
+
+    pub const DOUBLE_VERTICAL: Set = Set {
+        track: line::DOUBLE_VERTICAL,
+        thumb: block::FULL,
+    };
+
+    pub const DOUBLE_HORIZONTAL: Set = Set {
+        track: line::DOUBLE_HORIZONTAL,
+        thumb: block::FULL,
+    };
+
+    pub const SHADED: Set = Set {
+        track: "░",
+        thumb: block::FULL,
+    };
+}
+
 pub const DOT: &str = "•";
 
 pub mod braille {
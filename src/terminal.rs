@@ -1,10 +1,141 @@
 use std::io;
+use std::panic;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, Once, TryLockError};
+use std::time::{Duration, Instant};
 
-use crate::backend::Backend;
+use crate::backend::{Backend, CursorShape};
 use crate::buffer::Buffer;
 use crate::layout::Rect;
+use crate::theme::Theme;
 use crate::widgets::{StatefulWidget, Widget};
 
+/// Part of the screen managed by a [`Terminal`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Viewport {
+    /// The terminal takes over the whole screen.
+    Fullscreen,
+    /// The terminal only manages the given number of lines, starting at the row of the cursor
+    /// when the terminal is created. The screen is scrolled up if there is not enough room below
+    /// the cursor. When the terminal is dropped, the cursor is moved below the viewport so that
+    /// the last frame is left in the scrollback.
+    Inline(u16),
+}
+
+/// Options to pass to [`Terminal::with_options`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TerminalOptions {
+    /// Part of the screen the terminal draws to
+    pub viewport: Viewport,
+    /// Install a panic hook bringing the terminal back to its original state (main screen, raw
+    /// mode disabled, visible cursor) before the panic message is printed. Otherwise the message
+    /// would be lost when leaving the alternate screen. The hook is installed once for all the
+    /// terminals and only restores the ones which are not dropped yet.
+    pub restore_on_panic: bool,
+    /// Draw each frame within a synchronized update, so that terminals supporting them (DEC mode
+    /// 2026) only paint complete frames. Other terminals ignore the escape sequences.
+    pub synchronized_output: bool,
+    /// Scroll the lines which moved vertically since the previous frame with the scroll regions
+    /// of the terminal, when the backend supports them, instead of drawing them again.
+    pub scroll_regions: bool,
+    /// Maximum number of frames per second drawn by [`Terminal::draw_if_needed`], which does not
+    /// limit them when `None`.
+    pub max_fps: Option<u32>,
+}
+
+impl Default for TerminalOptions {
+    fn default() -> TerminalOptions {
+        TerminalOptions {
+            viewport: Viewport::Fullscreen,
+            restore_on_panic: false,
+            synchronized_output: false,
+            scroll_regions: false,
+            max_fps: None,
+        }
+    }
+}
+
+/// A cheap handle requesting a redraw of a [`Terminal`], which can be cloned and sent to other
+/// threads. All the requests made before the next frame is drawn are coalesced into that frame.
+///
+/// See [`Terminal::redraw_handle`] and [`Terminal::draw_if_needed`].
+#[derive(Debug, Clone)]
+pub struct RedrawHandle {
+    requested: Arc<AtomicBool>,
+}
+
+impl RedrawHandle {
+    /// Marks the terminal as needing a redraw.
+    pub fn request_redraw(&self) {
+        self.requested.store(true, Ordering::SeqCst);
+    }
+}
+
+/// A widget found at a given position of the screen by [`HitMap::hit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hit {
+    /// Id the widget was rendered with
+    pub id: usize,
+    /// Area the widget was rendered to
+    pub area: Rect,
+    /// Column of the position relative to the left of `area`
+    pub x: u16,
+    /// Row of the position relative to the top of `area`
+    pub y: u16,
+}
+
+/// Areas of the widgets rendered with an id during a draw call, in rendering order.
+///
+/// See [`Frame::render_widget_with_id`] and [`Terminal::hit_map`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HitMap {
+    areas: Vec<(usize, Rect)>,
+}
+
+impl HitMap {
+    /// Records that the widget with the given id was rendered to `area`.
+    pub fn insert(&mut self, id: usize, area: Rect) {
+        self.areas.push((id, area));
+    }
+
+    /// Finds the widget under the given position of the screen. Widgets rendered last are drawn
+    /// on top of the others so they take precedence when several areas overlap.
+    ///
+    /// Positions use the same coordinates as the mouse events of [`crate::event`], so a click can
+    /// be resolved with `hit_map.hit(event.column, event.row)`.
+    pub fn hit(&self, x: u16, y: u16) -> Option<Hit> {
+        self.areas
+            .iter()
+            .rev()
+            .find(|(_, area)| area.contains(x, y))
+            .map(|&(id, area)| Hit {
+                id,
+                area,
+                x: x - area.x,
+                y: y - area.y,
+            })
+    }
+
+    /// Returns the area the widget with the given id was last rendered to.
+    pub fn area(&self, id: usize) -> Option<Rect> {
+        self.areas
+            .iter()
+            .rev()
+            .find(|(i, _)| *i == id)
+            .map(|&(_, area)| area)
+    }
+
+    /// Returns whether no widget was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.areas.is_empty()
+    }
+
+    /// Forgets all the recorded widgets.
+    pub fn clear(&mut self) {
+        self.areas.clear();
+    }
+}
+
 /// Interface to the terminal backed by Termion
 #[derive(Debug)]
 pub struct Terminal<B>
@@ -15,12 +146,52 @@ where
     /// Holds the results of the current and previous draw calls. The two are compared at the end
     /// of each draw pass to output the necessary updates to the terminal
     buffers: [Buffer; 2],
-    /// Index of the current buffer in the previous array
+    /// Areas of the widgets rendered with an id during the current and previous draw calls
+    hit_maps: [HitMap; 2],
+    /// Index of the current buffer in the previous arrays
     current: usize,
     /// Whether the cursor is currently hidden
     hidden_cursor: bool,
-    /// Terminal size used for rendering.
-    known_size: Rect,
+    /// Whether the alternate screen is currently displayed
+    alternate_screen: bool,
+    /// Whether raw mode is currently enabled
+    raw_mode: bool,
+    /// Id of the function restoring this terminal on panic, if any
+    panic_restorer: Option<usize>,
+    /// Part of the screen managed by the terminal
+    viewport: Viewport,
+    /// Area of the screen currently used for rendering.
+    viewport_area: Rect,
+    /// Size of the screen the last time it was queried, used to detect resizes.
+    last_known_size: Rect,
+    /// Last known position of the cursor, used to find an inline viewport back after a resize.
+    last_known_cursor_pos: (u16, u16),
+    /// Whether frames are drawn within synchronized updates
+    synchronized_output: bool,
+    /// Whether the lines which moved since the previous frame are scrolled instead of drawn
+    scroll_regions: bool,
+    /// Position of the cursor requested by the last frame, hidden when `None`
+    frame_cursor: Option<(u16, u16)>,
+    /// Shape of the cursor requested by the last frame
+    frame_cursor_shape: CursorShape,
+    /// Current shape of the cursor
+    cursor_shape: CursorShape,
+    /// Whether a redraw was requested since the last frame was drawn
+    redraw_requested: Arc<AtomicBool>,
+    /// Minimum time between two frames drawn by `draw_if_needed`
+    min_frame_interval: Option<Duration>,
+    /// When the last frame was drawn
+    last_frame: Option<Instant>,
+}
+
+/// A frame drawn by [`Terminal::draw`] or [`Terminal::try_draw`], as it is displayed on the
+/// screen.
+#[derive(Debug, Clone)]
+pub struct CompletedFrame<'a> {
+    /// Content of the frame
+    pub buffer: &'a Buffer,
+    /// Area of the screen the frame was drawn to
+    pub area: Rect,
 }
 
 /// Represents a consistent terminal interface for rendering.
@@ -37,7 +208,33 @@ where
 {
     /// Terminal size, guaranteed not to change when rendering.
     pub fn size(&self) -> Rect {
-        self.terminal.known_size
+        self.terminal.viewport_area
+    }
+
+    /// Shows the cursor at the given position once the frame is drawn. The cursor is hidden after
+    /// drawing the frames which do not call this method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tui::backend::TestBackend;
+    /// # use tui::Terminal;
+    /// # let backend = TestBackend::new(10, 3);
+    /// let mut terminal = Terminal::new(backend).unwrap();
+    /// terminal.draw(|mut f| f.set_cursor(2, 1)).unwrap();
+    /// assert_eq!(terminal.backend().cursor_position(), Some((2, 1)));
+    /// terminal.draw(|_| {}).unwrap();
+    /// assert_eq!(terminal.backend().cursor_position(), None);
+    /// ```
+    pub fn set_cursor(&mut self, x: u16, y: u16) {
+        self.terminal.frame_cursor = Some((x, y));
+    }
+
+    /// Changes the shape of the cursor shown with [`Frame::set_cursor`] once the frame is drawn.
+    /// The frames which do not call this method use the shape configured by the user of the
+    /// terminal.
+    pub fn set_cursor_shape(&mut self, shape: CursorShape) {
+        self.terminal.frame_cursor_shape = shape;
     }
 
     /// Render a [`Widget`] to the current buffer using [`Widget::render`].
@@ -95,6 +292,51 @@ where
     {
         widget.render(area, self.terminal.current_buffer_mut(), state);
     }
+
+    /// Render a [`Widget`] like [`Frame::render_widget`] and record its area under the given id
+    /// in the [`HitMap`] of this frame.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::io;
+    /// # use tui::Terminal;
+    /// # use tui::backend::TermionBackend;
+    /// # use tui::layout::Rect;
+    /// # use tui::widgets::Block;
+    /// # let stdout = io::stdout();
+    /// # let backend = TermionBackend::new(stdout);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// const SIDEBAR: usize = 1;
+    /// terminal
+    ///     .draw(|mut f| f.render_widget_with_id(Block::default(), Rect::new(0, 0, 5, 5), SIDEBAR))
+    ///     .unwrap();
+    /// if let Some(hit) = terminal.hit_map().hit(2, 3) {
+    ///     assert_eq!(hit.id, SIDEBAR);
+    /// }
+    /// ```
+    pub fn render_widget_with_id<W>(&mut self, widget: W, area: Rect, id: usize)
+    where
+        W: Widget,
+    {
+        self.render_widget(widget, area);
+        self.terminal.hit_maps[self.terminal.current].insert(id, area);
+    }
+
+    /// Render a [`StatefulWidget`] like [`Frame::render_stateful_widget`] and record its area
+    /// under the given id in the [`HitMap`] of this frame.
+    pub fn render_stateful_widget_with_id<W>(
+        &mut self,
+        widget: W,
+        area: Rect,
+        state: &mut W::State,
+        id: usize,
+    ) where
+        W: StatefulWidget,
+    {
+        self.render_stateful_widget(widget, area, state);
+        self.terminal.hit_maps[self.terminal.current].insert(id, area);
+    }
 }
 
 impl<B> Drop for Terminal<B>
@@ -103,14 +345,124 @@ where
 {
     fn drop(&mut self) {
         // Attempt to restore the cursor state
+        if self.cursor_shape != CursorShape::Default {
+            if let Err(err) = self.backend.set_cursor_shape(CursorShape::Default) {
+                eprintln!("Failed to restore the cursor shape: {}", err);
+            }
+        }
         if self.hidden_cursor {
             if let Err(err) = self.show_cursor() {
                 eprintln!("Failed to show the cursor: {}", err);
             }
         }
+        // Move the cursor below an inline viewport so that the last frame stays in the scrollback
+        if let Viewport::Inline(_) = self.viewport {
+            let bottom = self.viewport_area.bottom().saturating_sub(1);
+            if let Err(err) = self
+                .backend
+                .set_cursor(self.viewport_area.left(), bottom)
+                .and_then(|_| self.backend.append_lines(1))
+                .and_then(|_| self.backend.flush())
+            {
+                eprintln!("Failed to move the cursor below the viewport: {}", err);
+            }
+        }
+        if self.alternate_screen {
+            if let Err(err) = self.leave_alternate_screen() {
+                eprintln!("Failed to leave the alternate screen: {}", err);
+            }
+        }
+        if self.raw_mode {
+            if let Err(err) = self.disable_raw_mode() {
+                eprintln!("Failed to disable raw mode: {}", err);
+            }
+        }
+        // The terminal is restored, the panic hook should not touch it anymore
+        if let Some(id) = self.panic_restorer {
+            unregister_panic_restorer(id);
+        }
     }
 }
 
+type PanicRestorer = Box<dyn Fn() + Send + Sync>;
+
+/// Functions restoring the live terminals created with `restore_on_panic`, by id
+static PANIC_RESTORERS: Mutex<Vec<(usize, PanicRestorer)>> = Mutex::new(Vec::new());
+static NEXT_PANIC_RESTORER_ID: AtomicUsize = AtomicUsize::new(0);
+static PANIC_HOOK: Once = Once::new();
+
+/// Registers a function restoring a terminal when a panic occurs, the panic hook calling the
+/// registered functions being chained to the current one the first time. Returns the id to
+/// unregister it with.
+fn register_panic_restorer(restore: PanicRestorer) -> usize {
+    PANIC_HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            // The lock is not waited for, the panic may come from a thread holding it
+            let restorers: Vec<_> = match PANIC_RESTORERS.try_lock() {
+                Ok(mut restorers) => restorers.drain(..).collect(),
+                Err(TryLockError::Poisoned(err)) => err.into_inner().drain(..).collect(),
+                Err(TryLockError::WouldBlock) => Vec::new(),
+            };
+            for (_, restore) in restorers {
+                restore();
+            }
+            previous_hook(info);
+        }));
+    });
+    let id = NEXT_PANIC_RESTORER_ID.fetch_add(1, Ordering::SeqCst);
+    lock_panic_restorers().push((id, restore));
+    id
+}
+
+fn unregister_panic_restorer(id: usize) {
+    lock_panic_restorers().retain(|&(other, _)| other != id);
+}
+
+fn lock_panic_restorers() -> MutexGuard<'static, Vec<(usize, PanicRestorer)>> {
+    // A restorer panicking poisons the lock, the list itself is still valid
+    PANIC_RESTORERS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+}
+
+/// Draws every cell of the buffer, overwriting whatever was displayed in its area.
+fn draw_all<B>(backend: &mut B, buffer: &Buffer) -> io::Result<()>
+where
+    B: Backend,
+{
+    // Blank the area first as drawing the cells hidden by multi-width symbols would erase them.
+    let blank = Buffer::empty(buffer.area);
+    backend.draw(blank.content.iter().enumerate().map(|(i, cell)| {
+        let (x, y) = blank.pos_of(i);
+        (x, y, cell)
+    }))?;
+    backend.draw(blank.diff(buffer).into_iter())
+}
+
+/// Computes the area of an inline viewport of the given height. The viewport starts `offset`
+/// lines above the current position of the cursor and the screen is scrolled up if there are not
+/// enough lines left below it. Also returns the position of the cursor before the scrolling.
+fn compute_inline_area<B>(
+    backend: &mut B,
+    height: u16,
+    size: Rect,
+    offset: u16,
+) -> io::Result<(Rect, (u16, u16))>
+where
+    B: Backend,
+{
+    let pos = backend.get_cursor()?;
+    let height = height.min(size.height);
+    let top = pos.1.saturating_sub(offset);
+    // Moving down from the top of the viewport to its last line scrolls the screen if needed.
+    backend.set_cursor(0, top)?;
+    backend.append_lines(height.saturating_sub(1))?;
+    let scrolled = (top + height).saturating_sub(size.height);
+    let area = Rect::new(size.x, top - scrolled, size.width, height);
+    Ok((area, pos))
+}
+
 impl<B> Terminal<B>
 where
     B: Backend,
@@ -118,14 +470,66 @@ where
     /// Wrapper around Termion initialization. Each buffer is initialized with a blank string and
     /// default colors for the foreground and the background
     pub fn new(backend: B) -> io::Result<Terminal<B>> {
+        Terminal::with_options(backend, TerminalOptions::default())
+    }
+
+    /// Creates a terminal with the given options, for example to only draw to a few lines below
+    /// the shell prompt instead of the whole screen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::io;
+    /// # use tui::backend::TermionBackend;
+    /// # use tui::{Terminal, TerminalOptions, Viewport};
+    /// # use termion::raw::IntoRawMode;
+    /// let stdout = io::stdout().into_raw_mode().unwrap();
+    /// let backend = TermionBackend::new(stdout);
+    /// let options = TerminalOptions {
+    ///     viewport: Viewport::Inline(8),
+    ///     ..TerminalOptions::default()
+    /// };
+    /// let mut terminal = Terminal::with_options(backend, options).unwrap();
+    /// ```
+    pub fn with_options(mut backend: B, options: TerminalOptions) -> io::Result<Terminal<B>> {
         let size = backend.size()?;
-        Ok(Terminal {
+        let (viewport_area, cursor_pos) = match options.viewport {
+            Viewport::Fullscreen => (size, (0, 0)),
+            Viewport::Inline(height) => compute_inline_area(&mut backend, height, size, 0)?,
+        };
+        let mut terminal = Terminal {
             backend,
-            buffers: [Buffer::empty(size), Buffer::empty(size)],
+            buffers: [Buffer::empty(viewport_area), Buffer::empty(viewport_area)],
+            hit_maps: [HitMap::default(), HitMap::default()],
             current: 0,
             hidden_cursor: false,
-            known_size: size,
-        })
+            alternate_screen: false,
+            raw_mode: false,
+            panic_restorer: None,
+            viewport: options.viewport,
+            viewport_area,
+            last_known_size: size,
+            last_known_cursor_pos: cursor_pos,
+            synchronized_output: options.synchronized_output,
+            scroll_regions: options.scroll_regions,
+            frame_cursor: None,
+            frame_cursor_shape: CursorShape::Default,
+            cursor_shape: CursorShape::Default,
+            redraw_requested: Arc::new(AtomicBool::new(true)),
+            min_frame_interval: options
+                .max_fps
+                .filter(|&fps| fps > 0)
+                .map(|fps| Duration::from_secs(1) / fps),
+            last_frame: None,
+        };
+        if let Viewport::Inline(_) = terminal.viewport {
+            // Lines below the cursor may still hold some old output
+            terminal.clear()?;
+        }
+        if options.restore_on_panic {
+            terminal.panic_restorer = Some(register_panic_restorer(terminal.backend.restorer()));
+        }
+        Ok(terminal)
     }
 
     /// Get a Frame object which provides a consistent view into the terminal state for rendering.
@@ -137,6 +541,23 @@ where
         &mut self.buffers[self.current]
     }
 
+    /// The theme the widgets are rendered with.
+    pub fn theme(&self) -> &Theme {
+        self.buffers[self.current].theme()
+    }
+
+    /// Changes the theme the widgets are rendered with, starting from the next draw call.
+    pub fn set_theme(&mut self, theme: Theme) {
+        for buffer in &mut self.buffers {
+            buffer.set_theme(theme);
+        }
+    }
+
+    /// Areas of the widgets rendered with an id during the last draw call.
+    pub fn hit_map(&self) -> &HitMap {
+        &self.hit_maps[1 - self.current]
+    }
+
     pub fn backend(&self) -> &B {
         &self.backend
     }
@@ -147,54 +568,421 @@ where
 
     /// Obtains a difference between the previous and the current buffer and passes it to the
     /// current backend for drawing.
+    ///
+    /// When [`TerminalOptions::scroll_regions`] is set and the backend supports scroll regions,
+    /// lines which only moved vertically since the previous frame are scrolled on the screen
+    /// first, so that only the lines scrolled into view are drawn.
     pub fn flush(&mut self) -> io::Result<()> {
+        self.draw_updates().map(|_| ())
+    }
+
+    /// Draws the difference between the previous and the current buffer, as [`Terminal::flush`]
+    /// does, and returns whether anything was written to the backend.
+    fn draw_updates(&mut self) -> io::Result<bool> {
+        let scrolled = self.scroll_regions
+            && self.backend.supports_scroll_regions()
+            && self.scroll_shifted_lines()?;
         let previous_buffer = &self.buffers[1 - self.current];
         let current_buffer = &self.buffers[self.current];
         let updates = previous_buffer.diff(current_buffer);
-        self.backend.draw(updates.into_iter())
+        match updates.last() {
+            Some(&(x, y, _)) => self.last_known_cursor_pos = (x, y),
+            None => return Ok(scrolled),
+        }
+        self.backend.draw(updates.into_iter())?;
+        Ok(true)
+    }
+
+    /// Scrolls the lines which moved between the previous and the current buffer, in both the
+    /// terminal and the previous buffer so that it keeps matching the screen. Returns whether
+    /// any line was scrolled.
+    fn scroll_shifted_lines(&mut self) -> io::Result<bool> {
+        let previous = 1 - self.current;
+        let shifts = self.buffers[previous].vertical_shifts(&self.buffers[self.current]);
+        let scrolled = !shifts.is_empty();
+        for (rows, lines) in shifts {
+            let amount = lines.unsigned_abs() as u16;
+            if lines > 0 {
+                self.backend.scroll_region_up(rows.clone(), amount)?;
+            } else {
+                self.backend.scroll_region_down(rows.clone(), amount)?;
+            }
+            self.buffers[previous].scroll_rows(rows, lines);
+        }
+        Ok(scrolled)
     }
 
     /// Updates the Terminal so that internal buffers match the requested size. Requested size will
     /// be saved so the size can remain consistent when rendering.
-    /// This leads to a full clear of the screen.
+    /// This leads to a full clear of the viewport.
+    ///
+    /// With an inline viewport, the viewport keeps its height and is moved so that it stays
+    /// below the shell prompt.
     pub fn resize(&mut self, area: Rect) -> io::Result<()> {
-        self.buffers[self.current].resize(area);
-        self.buffers[1 - self.current].reset();
-        self.buffers[1 - self.current].resize(area);
-        self.known_size = area;
-        self.backend.clear()
+        let viewport_area = match self.viewport {
+            Viewport::Fullscreen => area,
+            Viewport::Inline(height) => {
+                let offset = self
+                    .last_known_cursor_pos
+                    .1
+                    .saturating_sub(self.viewport_area.top());
+                let (viewport_area, cursor_pos) =
+                    compute_inline_area(&mut self.backend, height, area, offset)?;
+                self.last_known_cursor_pos = cursor_pos;
+                viewport_area
+            }
+        };
+        self.buffers[self.current].resize(viewport_area);
+        self.buffers[1 - self.current].resize(viewport_area);
+        self.viewport_area = viewport_area;
+        self.last_known_size = area;
+        self.clear()
     }
 
     /// Queries the backend for size and resizes if it doesn't match the previous size.
     pub fn autoresize(&mut self) -> io::Result<()> {
         let size = self.size()?;
-        if self.known_size != size {
+        if self.last_known_size != size {
             self.resize(size)?;
         }
         Ok(())
     }
 
     /// Synchronizes terminal size, calls the rendering closure, flushes the current internal state
-    /// and prepares for the next draw call.
-    pub fn draw<F>(&mut self, f: F) -> io::Result<()>
+    /// and prepares for the next draw call. Returns the frame which was drawn.
+    ///
+    /// The cursor is then shown where the frame asked for it with [`Frame::set_cursor`], or
+    /// hidden.
+    pub fn draw<F>(&mut self, f: F) -> io::Result<CompletedFrame<'_>>
     where
         F: FnOnce(Frame<B>),
+    {
+        let (frame, ()) = self.try_draw(|frame| {
+            f(frame);
+            Ok::<(), io::Error>(())
+        })?;
+        Ok(frame)
+    }
+
+    /// Draws a frame like [`Terminal::draw`] with a rendering closure which may fail. Its result is
+    /// returned along with the frame which was drawn. If it fails, the frame is dropped without
+    /// drawing anything and the error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::io;
+    /// # use tui::backend::TestBackend;
+    /// # use tui::widgets::{Block, Borders};
+    /// # use tui::Terminal;
+    /// # let backend = TestBackend::new(10, 3);
+    /// let mut terminal = Terminal::new(backend).unwrap();
+    /// let result = terminal.try_draw(|mut f| {
+    ///     let size = f.size();
+    ///     if size.width < 20 {
+    ///         return Err(io::Error::new(io::ErrorKind::InvalidInput, "screen too small"));
+    ///     }
+    ///     f.render_widget(Block::default().borders(Borders::ALL), size);
+    ///     Ok(size)
+    /// });
+    /// assert!(result.is_err());
+    /// ```
+    pub fn try_draw<F, T, E>(&mut self, f: F) -> Result<(CompletedFrame<'_>, T), E>
+    where
+        F: FnOnce(Frame<B>) -> Result<T, E>,
+        E: From<io::Error>,
     {
         // Autoresize - otherwise we get glitches if shrinking or potential desync between widgets
         // and the terminal (if growing), which may OOB.
         self.autoresize()?;
 
-        f(self.get_frame());
+        // Requests made while rendering call for another frame
+        self.redraw_requested.store(false, Ordering::SeqCst);
+        let cursor = self.frame_cursor.take();
+        let cursor_shape = std::mem::replace(&mut self.frame_cursor_shape, CursorShape::Default);
+        let value = match f(self.get_frame()) {
+            Ok(value) => value,
+            Err(err) => {
+                // The screen still displays the previous frame
+                self.buffers[self.current].reset();
+                self.hit_maps[self.current].clear();
+                self.frame_cursor = cursor;
+                self.frame_cursor_shape = cursor_shape;
+                self.request_redraw();
+                return Err(err);
+            }
+        };
+        self.last_frame = Some(Instant::now());
 
-        // Draw to stdout
-        self.flush()?;
+        // Draw to stdout, the cursor only being moved when the frame changed it or the drawing
+        // moved it away
+        let cursor_changed = self.frame_cursor_changed();
+        let mut changed = false;
+        self.synchronized(|terminal| {
+            changed = terminal.draw_updates()? || cursor_changed;
+            if changed {
+                terminal.apply_frame_cursor()?;
+            }
+            Ok(())
+        })?;
 
         // Swap buffers
         self.buffers[1 - self.current].reset();
+        self.hit_maps[1 - self.current].clear();
         self.current = 1 - self.current;
 
         // Flush
-        self.backend.flush()?;
+        if changed {
+            self.backend.flush()?;
+        }
+        let frame = CompletedFrame {
+            buffer: &self.buffers[1 - self.current],
+            area: self.viewport_area,
+        };
+        Ok((frame, value))
+    }
+
+    /// Inserts some lines above an inline viewport. They are drawn by `draw_fn` into a buffer of
+    /// the given height and of the width of the viewport, and pushed to the scrollback, above the
+    /// viewport. The viewport is then moved down and the last frame is drawn again so that the
+    /// next call to [`Terminal::draw`] only outputs the differences, as usual.
+    ///
+    /// This has no effect when the terminal is drawing to the whole screen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tui::backend::TestBackend;
+    /// # use tui::style::Style;
+    /// # use tui::{Terminal, TerminalOptions, Viewport};
+    /// # let backend = TestBackend::new(20, 10);
+    /// let options = TerminalOptions {
+    ///     viewport: Viewport::Inline(3),
+    ///     ..TerminalOptions::default()
+    /// };
+    /// let mut terminal = Terminal::with_options(backend, options).unwrap();
+    /// terminal
+    ///     .insert_before(1, |buf| {
+    ///         buf.set_string(0, 0, "Compiling tui", Style::default());
+    ///     })
+    ///     .unwrap();
+    /// ```
+    pub fn insert_before<F>(&mut self, height: u16, draw_fn: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut Buffer),
+    {
+        if self.viewport == Viewport::Fullscreen {
+            return Ok(());
+        }
+        let mut buffer = Buffer::empty(Rect::new(0, 0, self.viewport_area.width, height));
+        buffer.set_theme(*self.theme());
+        draw_fn(&mut buffer);
+        self.synchronized(|terminal| {
+            terminal.insert_buffer_before(&buffer)?;
+            terminal.apply_frame_cursor()
+        })?;
+        self.backend.flush()
+    }
+
+    /// Returns a handle requesting a redraw, see [`Terminal::draw_if_needed`].
+    pub fn redraw_handle(&self) -> RedrawHandle {
+        RedrawHandle {
+            requested: self.redraw_requested.clone(),
+        }
+    }
+
+    /// Marks the terminal as needing a redraw, see [`Terminal::draw_if_needed`].
+    pub fn request_redraw(&self) {
+        self.redraw_requested.store(true, Ordering::SeqCst);
+    }
+
+    /// How long to wait before [`Terminal::draw_if_needed`] draws the next frame, given the
+    /// maximum frame rate, or `None` when no redraw was requested. Event loops can use it as the
+    /// timeout of their next wait for events.
+    pub fn time_until_next_frame(&self) -> Option<Duration> {
+        if !self.redraw_requested.load(Ordering::SeqCst) {
+            return None;
+        }
+        let elapsed = self.last_frame.map(|last_frame| last_frame.elapsed());
+        Some(match (self.min_frame_interval, elapsed) {
+            (Some(interval), Some(elapsed)) => interval.checked_sub(elapsed).unwrap_or_default(),
+            _ => Duration::default(),
+        })
+    }
+
+    /// Draws a frame like [`Terminal::draw`] if a redraw was requested since the last one, with
+    /// [`Terminal::request_redraw`] or a [`RedrawHandle`], or if the terminal was resized. Frames
+    /// are drawn at most [`TerminalOptions::max_fps`] times per second: the pending requests are
+    /// kept for a later call otherwise. Returns the frame if one was drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::thread;
+    /// # use tui::backend::TestBackend;
+    /// # use tui::widgets::{Block, Borders};
+    /// # use tui::{Terminal, TerminalOptions};
+    /// # let backend = TestBackend::new(10, 3);
+    /// let options = TerminalOptions {
+    ///     max_fps: Some(30),
+    ///     ..TerminalOptions::default()
+    /// };
+    /// let mut terminal = Terminal::with_options(backend, options).unwrap();
+    /// let handle = terminal.redraw_handle();
+    /// thread::spawn(move || handle.request_redraw()).join().unwrap();
+    ///
+    /// let render = |mut f: tui::Frame<_>| f.render_widget(Block::default(), f.size());
+    /// assert!(terminal.draw_if_needed(render).unwrap().is_some());
+    /// assert!(terminal.draw_if_needed(render).unwrap().is_none());
+    /// ```
+    pub fn draw_if_needed<F>(&mut self, f: F) -> io::Result<Option<CompletedFrame<'_>>>
+    where
+        F: FnOnce(Frame<B>),
+    {
+        if self.size()? != self.last_known_size {
+            self.request_redraw();
+        }
+        if self.time_until_next_frame() != Some(Duration::default()) {
+            return Ok(None);
+        }
+        self.draw(f).map(Some)
+    }
+
+    /// Whether the cursor requested by the current frame is not the one on the screen.
+    fn frame_cursor_changed(&self) -> bool {
+        match self.frame_cursor {
+            Some(pos) => {
+                self.hidden_cursor
+                    || self.last_known_cursor_pos != pos
+                    || self.cursor_shape != self.frame_cursor_shape
+            }
+            None => !self.hidden_cursor,
+        }
+    }
+
+    /// Shows the cursor where the last frame asked for it, with the requested shape, or hides it.
+    fn apply_frame_cursor(&mut self) -> io::Result<()> {
+        match self.frame_cursor {
+            Some((x, y)) => {
+                if self.cursor_shape != self.frame_cursor_shape {
+                    self.backend.set_cursor_shape(self.frame_cursor_shape)?;
+                    self.cursor_shape = self.frame_cursor_shape;
+                }
+                self.set_cursor(x, y)?;
+                if self.hidden_cursor {
+                    self.show_cursor()?;
+                }
+            }
+            None => {
+                if !self.hidden_cursor {
+                    self.hide_cursor()?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Pushes the lines of `buffer` above the viewport and draws the last frame below them.
+    fn insert_buffer_before(&mut self, buffer: &Buffer) -> io::Result<()> {
+        let height = buffer.area.height;
+        // Lines are inserted in chunks small enough to keep the viewport on the screen.
+        let screen_bottom = self.last_known_size.bottom();
+        let max_lines = self
+            .last_known_size
+            .height
+            .saturating_sub(self.viewport_area.height);
+        let width = buffer.area.width as usize;
+        let mut area = self.viewport_area;
+        let mut row = 0;
+        if max_lines == 0 {
+            // The viewport fills the screen: each line is drawn over its first line, which is
+            // then scrolled out of the screen
+            while row < height {
+                let mut chunk = Buffer::empty(Rect::new(area.x, area.y, area.width, 1));
+                let start = row as usize * width;
+                chunk
+                    .content
+                    .clone_from_slice(&buffer.content[start..start + width]);
+                draw_all(&mut self.backend, &chunk)?;
+                self.backend
+                    .set_cursor(area.left(), area.bottom().saturating_sub(1))?;
+                self.backend.append_lines(1)?;
+                row += 1;
+            }
+        }
+        while row < height {
+            let lines = std::cmp::min(max_lines, height - row);
+            // Make room below the viewport, scrolling the screen up if needed
+            self.backend
+                .set_cursor(area.left(), area.bottom().saturating_sub(1))?;
+            self.backend.append_lines(lines)?;
+            let scrolled = (area.bottom() + lines).saturating_sub(screen_bottom);
+            let mut chunk = Buffer::empty(Rect::new(area.x, area.y - scrolled, area.width, lines));
+            let start = row as usize * width;
+            chunk
+                .content
+                .clone_from_slice(&buffer.content[start..start + lines as usize * width]);
+            draw_all(&mut self.backend, &chunk)?;
+            area.y = area.y + lines - scrolled;
+            row += lines;
+        }
+
+        // Draw the last frame at the new position of the viewport. The previous buffer still
+        // matches what is displayed so the next diff stays valid.
+        self.buffers[self.current].resize(area);
+        self.buffers[1 - self.current].resize(area);
+        // The cursor requested by the last frame moves along with the viewport
+        let moved = area.y - self.viewport_area.y;
+        if let Some((_, ref mut y)) = self.frame_cursor {
+            *y += moved;
+        }
+        self.viewport_area = area;
+        draw_all(&mut self.backend, &self.buffers[1 - self.current])?;
+        self.set_cursor(area.left(), area.top())
+    }
+
+    /// Calls `f` within a synchronized update of the backend if synchronized output is enabled.
+    fn synchronized<F>(&mut self, f: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut Terminal<B>) -> io::Result<()>,
+    {
+        if !self.synchronized_output {
+            return f(self);
+        }
+        self.backend.begin_synchronized_update()?;
+        let result = f(self);
+        // The update is ended even if drawing failed, the terminal would stop painting otherwise
+        let end = self.backend.end_synchronized_update();
+        result.and(end)
+    }
+
+    /// Switches to the alternate screen. The main screen is restored when the terminal is dropped,
+    /// unless [`Terminal::leave_alternate_screen`] has been called before.
+    pub fn enter_alternate_screen(&mut self) -> io::Result<()> {
+        self.backend.enter_alternate_screen()?;
+        self.alternate_screen = true;
+        self.clear()
+    }
+
+    pub fn leave_alternate_screen(&mut self) -> io::Result<()> {
+        self.backend.leave_alternate_screen()?;
+        self.alternate_screen = false;
+        // The main screen does not match the previous buffer
+        self.buffers[1 - self.current].reset();
+        Ok(())
+    }
+
+    /// Enables raw mode. It is disabled when the terminal is dropped, unless
+    /// [`Terminal::disable_raw_mode`] has been called before.
+    pub fn enable_raw_mode(&mut self) -> io::Result<()> {
+        self.backend.enable_raw_mode()?;
+        self.raw_mode = true;
+        Ok(())
+    }
+
+    pub fn disable_raw_mode(&mut self) -> io::Result<()> {
+        self.backend.disable_raw_mode()?;
+        self.raw_mode = false;
         Ok(())
     }
 
@@ -209,13 +997,30 @@ where
         Ok(())
     }
     pub fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
-        self.backend.get_cursor()
+        let pos = self.backend.get_cursor()?;
+        self.last_known_cursor_pos = pos;
+        Ok(pos)
     }
     pub fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
-        self.backend.set_cursor(x, y)
+        self.backend.set_cursor(x, y)?;
+        self.last_known_cursor_pos = (x, y);
+        Ok(())
     }
+    /// Clears the viewport and forces a full redraw on the next draw call.
+    ///
+    /// With an inline viewport, only the lines of the viewport are cleared.
     pub fn clear(&mut self) -> io::Result<()> {
-        self.backend.clear()
+        match self.viewport {
+            Viewport::Fullscreen => self.backend.clear()?,
+            Viewport::Inline(_) => {
+                let blank = Buffer::empty(self.viewport_area);
+                self.synchronized(|terminal| draw_all(&mut terminal.backend, &blank))?;
+                self.backend.flush()?;
+            }
+        }
+        // The screen does not match the previous buffer anymore
+        self.buffers[1 - self.current].reset();
+        Ok(())
     }
     /// Queries the real size of the backend.
     pub fn size(&self) -> io::Result<Rect> {
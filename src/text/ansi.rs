@@ -0,0 +1,259 @@
+//! Conversion of text containing ANSI escape sequences into styled [`Text`].
+//!
+//! Only SGR sequences (`ESC [ ... m`) change the style of the text: colors (the 16 named colors,
+//! 256 indexed colors and truecolor), bold, dim, italic, underlines with their style and color,
+//! blink, reverse, hidden, crossed out and reset. Other escape sequences, such as cursor movements
+//! or window titles, are removed.
+//!
+//! ```rust
+//! # use tui::text::{ansi, Span, Spans};
+//! # use tui::style::{Color, Modifier, Style};
+//! let text = ansi::parse("\u{1b}[1;31merror\u{1b}[0m: not found");
+//! assert_eq!(
+//!     text.lines,
+//!     vec![Spans::from(vec![
+//!         Span::styled("error", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+//!         Span::raw(": not found"),
+//!     ])]
+//! );
+//! ```
+use std::borrow::Cow;
+
+use crate::style::{Color, Modifier, Style};
+use crate::text::Text;
+
+const ESC: char = '\u{1b}';
+const BEL: char = '\u{7}';
+
+/// Parses a string containing ANSI escape sequences. The style set by a sequence applies until
+/// the next one, across lines. Text outside of any sequence has the default style, so that it is
+/// drawn with the style of the widget, and the default colors (`39` and `49`) are the ones of the
+/// widget.
+pub fn parse(input: &str) -> Text<'_> {
+    let mut text = Text::default();
+    let mut style = Style::default();
+    let mut rest = input;
+    while let Some(start) = rest.find(ESC) {
+        if start > 0 {
+            text.push(Cow::Borrowed(&rest[..start]), style);
+        }
+        let (sgr, len) = escape_sequence(&rest[start..]);
+        if let Some(params) = sgr {
+            style = apply_sgr(style, params);
+        }
+        rest = &rest[start + len..];
+    }
+    if !rest.is_empty() || text.lines.is_empty() {
+        text.push(Cow::Borrowed(rest), style);
+    }
+    text.trim_last_line();
+    text
+}
+
+/// Reads the escape sequence at the start of `s`. Returns the parameters of the sequence if it
+/// is an SGR sequence, and the length of the sequence.
+fn escape_sequence(s: &str) -> (Option<&str>, usize) {
+    let mut chars = s.char_indices().skip(1);
+    match chars.next() {
+        // Control Sequence Introducer: parameters and intermediate bytes, then a final byte
+        Some((_, '[')) => {
+            for (i, c) in chars {
+                if ('\u{40}'..='\u{7e}').contains(&c) {
+                    let sgr = if c == 'm' { Some(&s[2..i]) } else { None };
+                    return (sgr, i + 1);
+                }
+            }
+            (None, s.len())
+        }
+        // Operating System Command: terminated by BEL or ESC \
+        Some((_, ']')) => {
+            let mut previous = None;
+            for (i, c) in chars {
+                if c == BEL {
+                    return (None, i + 1);
+                }
+                if previous == Some(ESC) && c == '\\' {
+                    return (None, i + 1);
+                }
+                previous = Some(c);
+            }
+            (None, s.len())
+        }
+        // Two-character sequence
+        Some((i, c)) => (None, i + c.len_utf8()),
+        None => (None, s.len()),
+    }
+}
+
+/// Applies the parameters of an SGR sequence to a style. Unknown parameters are ignored.
+fn apply_sgr(mut style: Style, params: &str) -> Style {
+    // Parameters are separated by `;`, a parameter may have sub-parameters separated by `:`
+    let mut params = params.split(';');
+    while let Some(param) = params.next() {
+        let mut parts = param.split(':').map(|p| p.parse::<u16>().ok());
+        match parts.next().flatten().unwrap_or(0) {
+            0 => style = Style::default(),
+            1 => style.add_modifier.insert(Modifier::BOLD),
+            2 => style.add_modifier.insert(Modifier::DIM),
+            3 => style.add_modifier.insert(Modifier::ITALIC),
+            4 => {
+                style.add_modifier.remove(Modifier::ALL_UNDERLINES);
+                style
+                    .add_modifier
+                    .insert(underline(parts.next().flatten().unwrap_or(1)));
+            }
+            5 => style.add_modifier.insert(Modifier::SLOW_BLINK),
+            6 => style.add_modifier.insert(Modifier::RAPID_BLINK),
+            7 => style.add_modifier.insert(Modifier::REVERSED),
+            8 => style.add_modifier.insert(Modifier::HIDDEN),
+            9 => style.add_modifier.insert(Modifier::CROSSED_OUT),
+            21 => {
+                style.add_modifier.remove(Modifier::ALL_UNDERLINES);
+                style.add_modifier.insert(Modifier::DOUBLE_UNDERLINED);
+            }
+            22 => style.add_modifier.remove(Modifier::BOLD | Modifier::DIM),
+            23 => style.add_modifier.remove(Modifier::ITALIC),
+            24 => style.add_modifier.remove(Modifier::ALL_UNDERLINES),
+            25 => style
+                .add_modifier
+                .remove(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK),
+            27 => style.add_modifier.remove(Modifier::REVERSED),
+            28 => style.add_modifier.remove(Modifier::HIDDEN),
+            29 => style.add_modifier.remove(Modifier::CROSSED_OUT),
+            n @ 30..=37 => style.fg = Some(named_color(n - 30)),
+            38 => style.fg = extended_color(parts, &mut params).or(style.fg),
+            39 => style.fg = None,
+            n @ 40..=47 => style.bg = Some(named_color(n - 40)),
+            48 => style.bg = extended_color(parts, &mut params).or(style.bg),
+            49 => style.bg = None,
+            58 => {
+                style.underline_color = extended_color(parts, &mut params).or(style.underline_color)
+            }
+            59 => style.underline_color = None,
+            n @ 90..=97 => style.fg = Some(named_color(n - 90 + 8)),
+            n @ 100..=107 => style.bg = Some(named_color(n - 100 + 8)),
+            _ => {}
+        }
+    }
+    style
+}
+
+/// The underline drawn for a sub-parameter of `4` (`4:3` is a curly underline), `0` and unknown
+/// styles drawing none.
+fn underline(n: u16) -> Modifier {
+    match n {
+        1 => Modifier::UNDERLINED,
+        2 => Modifier::DOUBLE_UNDERLINED,
+        3 => Modifier::UNDERCURLED,
+        4 => Modifier::UNDERDOTTED,
+        5 => Modifier::UNDERDASHED,
+        _ => Modifier::empty(),
+    }
+}
+
+/// Reads the color of a 38, 48 or 58 parameter: `5;n` for an indexed color or `2;r;g;b` for a
+/// truecolor. The parts are either the sub-parameters (`38:2:r:g:b`, possibly with a color space
+/// before the components as in `38:2::r:g:b`) or the following parameters.
+fn extended_color<'a, I, P>(subparams: I, params: &mut P) -> Option<Color>
+where
+    I: Iterator<Item = Option<u16>>,
+    P: Iterator<Item = &'a str>,
+{
+    let mut parts: Vec<Option<u16>> = subparams.collect();
+    if parts.is_empty() {
+        let mut next = || params.next().and_then(|p| p.parse().ok());
+        parts.push(next());
+        let count = match parts[0] {
+            Some(5) => 1,
+            Some(2) => 3,
+            _ => 0,
+        };
+        for _ in 0..count {
+            parts.push(next());
+        }
+    }
+    let component = |p: Option<u16>| p.map(|p| p.min(255) as u8);
+    match parts[..] {
+        [Some(5), n, ..] => component(n).map(Color::Indexed),
+        [Some(2), _, r, g, b, ..] | [Some(2), r, g, b] => {
+            Some(Color::Rgb(component(r)?, component(g)?, component(b)?))
+        }
+        _ => None,
+    }
+}
+
+/// One of the 16 colors of the terminal, the 8 last ones being the bright variants.
+fn named_color(n: u16) -> Color {
+    match n {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::Gray,
+        8 => Color::DarkGray,
+        9 => Color::LightRed,
+        10 => Color::LightGreen,
+        11 => Color::LightYellow,
+        12 => Color::LightBlue,
+        13 => Color::LightMagenta,
+        14 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::{Span, Spans};
+
+    #[test]
+    fn ansi_parse_colors_across_lines() {
+        let text =
+            parse("\u{1b}[38;5;208mdiff\n\u{1b}[1m+added\u{1b}[22;39m\n\u{1b}[48;2;1;2;3mbg");
+        let orange = Style::default().fg(Color::Indexed(208));
+        assert_eq!(
+            text.lines,
+            vec![
+                Spans::from(Span::styled("diff", orange)),
+                Spans::from(Span::styled("+added", orange.add_modifier(Modifier::BOLD))),
+                Spans::from(Span::styled("bg", Style::default().bg(Color::Rgb(1, 2, 3)))),
+            ]
+        );
+    }
+
+    #[test]
+    fn ansi_parse_underlines() {
+        let text = parse("\u{1b}[4:3;58:2::255:0:0ma\u{1b}[21;58;5;4mb\u{1b}[4:0;59mc");
+        assert_eq!(
+            text.lines,
+            vec![Spans(vec![
+                Span::styled(
+                    "a",
+                    Style::default()
+                        .underline_color(Color::Rgb(255, 0, 0))
+                        .add_modifier(Modifier::UNDERCURLED)
+                ),
+                Span::styled(
+                    "b",
+                    Style::default()
+                        .underline_color(Color::Indexed(4))
+                        .add_modifier(Modifier::DOUBLE_UNDERLINED)
+                ),
+                Span::raw("c"),
+            ])]
+        );
+    }
+
+    #[test]
+    fn ansi_parse_removes_other_sequences() {
+        let text = parse("\u{1b}]0;title\u{7}\u{1b}[2Kdone\u{1b}[\u{1b}(Bx\u{1b}[m\u{1b}[97m");
+        assert_eq!(
+            text.lines,
+            vec![Spans(vec![Span::raw("done"), Span::raw("x")])]
+        );
+        assert_eq!(parse("").lines, vec![Spans::default()]);
+    }
+}
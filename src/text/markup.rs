@@ -0,0 +1,324 @@
+//! A lightweight markup to style parts of a text inline.
+//!
+//! A tag starts with `{`, followed by some attributes, a single space and the tagged text, and
+//! ends with `}`. Attributes are `key=value` pairs separated by `;`:
+//!
+//! * `fg`, `bg` and `ul` set the foreground, background and underline colors. A color is either a
+//!   name (`red`, `dark_gray`, `light_blue`, `reset`...), an index in the 256 colors palette
+//!   (`208`) or a truecolor (`#ff8000`).
+//! * `mod` adds some modifiers, separated by `|` (`bold|italic`). Modifiers are named after the
+//!   constants of [`Modifier`] (`bold`, `dim`, `italic`, `underlined`, `slow_blink`,
+//!   `rapid_blink`, `reversed`, `hidden`, `crossed_out`, `double_underlined`, `undercurled`,
+//!   `underdotted`, `underdashed`).
+//! * `remove` removes some modifiers from the style of the enclosing text, with the same syntax.
+//!
+//! Tags can be nested and span several lines, the inner tags being applied on top of the outer
+//! ones. `\{`, `\}` and `\\` insert the character following the backslash, any other backslash
+//! is kept as is.
+//!
+//! ```rust
+//! # use tui::text::{markup, Span, Spans};
+//! # use tui::style::{Color, Modifier, Style};
+//! let text = markup::parse("{fg=red;mod=bold error}: {mod=italic \\{path\\}}").unwrap();
+//! assert_eq!(
+//!     text.lines,
+//!     vec![Spans::from(vec![
+//!         Span::styled("error", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+//!         Span::raw(": "),
+//!         Span::styled("{", Style::default().add_modifier(Modifier::ITALIC)),
+//!         Span::styled("path", Style::default().add_modifier(Modifier::ITALIC)),
+//!         Span::styled("}", Style::default().add_modifier(Modifier::ITALIC)),
+//!     ])]
+//! );
+//!
+//! let err = markup::parse("{fg=redd oops}").unwrap_err();
+//! assert_eq!(err.to_string(), "line 1, column 1: invalid color `redd`");
+//! ```
+use std::error;
+use std::fmt;
+
+use crate::style::{ParseStyleError, Style};
+use crate::text::{Span, Spans, Text};
+
+/// The reason why some markup could not be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    /// A tag is not closed by a `}`
+    UnclosedTag,
+    /// A `}` does not close any tag
+    UnexpectedClosingBrace,
+    /// The attributes of a tag are not followed by a space
+    UnterminatedAttributes,
+    /// An attribute is not a `key=value` pair with a known key
+    InvalidAttribute(String),
+    /// The value of a `fg`, `bg` or `ul` attribute is not a color
+    InvalidColor(String),
+    /// The value of a `mod` attribute is not a list of modifiers
+    InvalidModifier(String),
+}
+
+impl From<ParseStyleError> for ErrorKind {
+    fn from(err: ParseStyleError) -> ErrorKind {
+        match err {
+            ParseStyleError::InvalidColor(c) => ErrorKind::InvalidColor(c),
+            ParseStyleError::InvalidModifier(m) => ErrorKind::InvalidModifier(m),
+            ParseStyleError::InvalidAttribute(a) => ErrorKind::InvalidAttribute(a),
+        }
+    }
+}
+
+/// An error in some markup, located by its line and column (in characters), both starting at 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ErrorKind::UnclosedTag => write!(f, "tag is never closed"),
+            ErrorKind::UnexpectedClosingBrace => write!(f, "`}}` does not close any tag"),
+            ErrorKind::UnterminatedAttributes => {
+                write!(f, "tag attributes must be followed by a space")
+            }
+            ErrorKind::InvalidAttribute(ref a) => write!(f, "invalid attribute `{}`", a),
+            ErrorKind::InvalidColor(ref c) => write!(f, "invalid color `{}`", c),
+            ErrorKind::InvalidModifier(ref m) => write!(f, "invalid modifier `{}`", m),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl error::Error for Error {}
+
+/// Parses a string containing some markup. Text outside of any tag has the default style, so
+/// that it is drawn with the style of the widget.
+pub fn parse(input: &str) -> Result<Text<'_>, Error> {
+    let mut parser = Parser::default();
+    for (i, line) in input.split('\n').enumerate() {
+        if i > 0 {
+            parser.new_line();
+        }
+        parser.parse_span(line, Style::default())?;
+    }
+    let mut text = parser.finish()?;
+    text.trim_last_line();
+    Ok(text)
+}
+
+/// Parses the markup contained in the spans of a text. Tags are [patched](Style::patch) on top
+/// of the style of the spans and may start and end in different spans.
+pub fn parse_text<'b>(text: &'b Text<'_>) -> Result<Text<'b>, Error> {
+    let mut parser = Parser::default();
+    for (i, line) in text.lines.iter().enumerate() {
+        if i > 0 {
+            parser.new_line();
+        }
+        for span in &line.0 {
+            parser.parse_span(&span.content, span.style)?;
+        }
+    }
+    parser.finish()
+}
+
+/// The attributes of an open tag.
+#[derive(Debug)]
+struct Tag {
+    style: Style,
+    line: usize,
+    column: usize,
+}
+
+/// Incremental parser, fed with the spans of each line.
+#[derive(Debug, Default)]
+struct Parser<'b> {
+    lines: Vec<Spans<'b>>,
+    current: Vec<Span<'b>>,
+    tags: Vec<Tag>,
+    /// Current line, starting at 0
+    line: usize,
+    /// Number of characters already parsed on the current line
+    column: usize,
+}
+
+impl<'b> Parser<'b> {
+    /// Parses the content of a span, the text outside of any tag keeping the given style.
+    fn parse_span(&mut self, content: &'b str, base: Style) -> Result<(), Error> {
+        let mut rest = content;
+        while let Some(i) = rest.find(&['{', '}', '\\'][..]) {
+            let position = content.len() - rest.len() + i;
+            match rest.as_bytes()[i] {
+                b'\\' => match rest[i + 1..].chars().next() {
+                    Some('{') | Some('}') | Some('\\') => {
+                        self.push(&rest[..i], base);
+                        self.push(&rest[i + 1..i + 2], base);
+                        rest = &rest[i + 2..];
+                    }
+                    _ => {
+                        self.push(&rest[..i + 1], base);
+                        rest = &rest[i + 1..];
+                    }
+                },
+                b'{' => {
+                    self.push(&rest[..i], base);
+                    let tag = &rest[i + 1..];
+                    match tag.find(&[' ', '{', '}', '\\'][..]) {
+                        Some(end) if tag[end..].starts_with(' ') => {
+                            let tag = self.tag(&tag[..end], content, position)?;
+                            self.tags.push(tag);
+                            rest = &rest[i + 1 + end + 1..];
+                        }
+                        _ => {
+                            return Err(self.error(
+                                content,
+                                position,
+                                ErrorKind::UnterminatedAttributes,
+                            ));
+                        }
+                    }
+                }
+                _ => {
+                    self.push(&rest[..i], base);
+                    if self.tags.pop().is_none() {
+                        return Err(self.error(
+                            content,
+                            position,
+                            ErrorKind::UnexpectedClosingBrace,
+                        ));
+                    }
+                    rest = &rest[i + 1..];
+                }
+            }
+        }
+        self.push(rest, base);
+        self.column += content.chars().count();
+        Ok(())
+    }
+
+    fn new_line(&mut self) {
+        let spans = std::mem::take(&mut self.current);
+        self.lines.push(Spans(spans));
+        self.line += 1;
+        self.column = 0;
+    }
+
+    /// Returns the parsed text, or an error if a tag is still open.
+    fn finish(mut self) -> Result<Text<'b>, Error> {
+        if let Some(tag) = self.tags.first() {
+            return Err(Error {
+                line: tag.line,
+                column: tag.column,
+                kind: ErrorKind::UnclosedTag,
+            });
+        }
+        self.new_line();
+        Ok(Text { lines: self.lines })
+    }
+
+    fn push(&mut self, s: &'b str, base: Style) {
+        if s.is_empty() {
+            return;
+        }
+        let style = self
+            .tags
+            .iter()
+            .fold(base, |style, tag| style.patch(tag.style));
+        self.current.push(Span::styled(s, style));
+    }
+
+    /// Parses the attributes of a tag starting at `position` in `content`.
+    fn tag(&self, attributes: &str, content: &str, position: usize) -> Result<Tag, Error> {
+        let style = parse_style(attributes).map_err(|kind| self.error(content, position, kind))?;
+        Ok(Tag {
+            style,
+            line: self.line + 1,
+            column: self.column + content[..position].chars().count() + 1,
+        })
+    }
+
+    fn error(&self, content: &str, position: usize, kind: ErrorKind) -> Error {
+        Error {
+            line: self.line + 1,
+            column: self.column + content[..position].chars().count() + 1,
+            kind,
+        }
+    }
+}
+
+/// Parses the `key=value` attributes of a tag, separated by `;`, into a style.
+pub(crate) fn parse_style(attributes: &str) -> Result<Style, ErrorKind> {
+    if attributes.is_empty() {
+        return Err(ErrorKind::InvalidAttribute(String::new()));
+    }
+    attributes.parse().map_err(ErrorKind::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{Color, Modifier};
+
+    #[test]
+    fn markup_parse_nested_tags_across_lines() {
+        let text = parse("a{fg=#ff8000 b\n{bg=4;mod=bold|underlined c}}d\n").unwrap();
+        let orange = Style::default().fg(Color::Rgb(255, 128, 0));
+        assert_eq!(
+            text.lines,
+            vec![
+                Spans::from(vec![Span::raw("a"), Span::styled("b", orange)]),
+                Spans::from(vec![
+                    Span::styled(
+                        "c",
+                        orange
+                            .bg(Color::Indexed(4))
+                            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+                    ),
+                    Span::raw("d"),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn markup_parse_reports_errors() {
+        let error = |line, column, kind| Err(Error { line, column, kind });
+        assert_eq!(
+            parse("ok\n  {fg=red x"),
+            error(2, 3, ErrorKind::UnclosedTag)
+        );
+        assert_eq!(parse("x}"), error(1, 2, ErrorKind::UnexpectedClosingBrace));
+        assert_eq!(
+            parse("é{fg=red}"),
+            error(1, 2, ErrorKind::UnterminatedAttributes)
+        );
+        assert_eq!(
+            parse("{fg=red;size=2 x}"),
+            error(1, 1, ErrorKind::InvalidAttribute("size=2".to_owned()))
+        );
+        assert_eq!(
+            parse("{mod=bold|fat x}"),
+            error(1, 1, ErrorKind::InvalidModifier("fat".to_owned()))
+        );
+        assert_eq!(
+            parse("a\\b \\{ \\\\").map(|t| t.lines),
+            Ok(vec![Spans(vec![
+                Span::raw("a\\"),
+                Span::raw("b "),
+                Span::raw("{"),
+                Span::raw(" "),
+                Span::raw("\\"),
+            ])])
+        );
+    }
+}
@@ -0,0 +1,361 @@
+//! Primitives for styled text.
+//!
+//! A [`Span`] is a piece of text with a single style, [`Spans`] is a line made of several spans
+//! and [`Text`] is a list of lines. Widgets taking some text accept anything that converts into
+//! the type they need, so a plain `&str` or `String` works everywhere:
+//!
+//! ```rust
+//! # use tui::text::{Span, Spans, Text};
+//! # use tui::style::{Color, Modifier, Style};
+//! let line = Spans::from(vec![
+//!     Span::styled("error", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+//!     Span::raw(": file not found"),
+//! ]);
+//! assert_eq!(line.width(), 21);
+//!
+//! let text = Text::from("first line\nsecond line");
+//! assert_eq!(text.height(), 2);
+//! ```
+//!
+//! Spans created with [`Span::raw`] have the default style and are drawn with the style of the
+//! widget displaying them. The style of other spans is [patched](Style::patch) on top of the style
+//! of the widget, so that a span only setting a foreground color keeps the background of the
+//! widget. The fragments of [`widgets::Text`](crate::widgets::Text) convert into
+//! a [`Text`], one line per `\n`.
+//!
+//! The [`ansi`] module turns the output of commands using ANSI escape sequences into a [`Text`],
+//! the [`markup`] module parses a lightweight markup styling parts of a text inline.
+use std::borrow::Cow;
+use std::slice;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::style::Style;
+use crate::widgets;
+
+pub mod ansi;
+pub mod markup;
+
+/// A string with a single style.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span<'a> {
+    pub content: Cow<'a, str>,
+    pub style: Style,
+    /// Target of the hyperlink drawn by the span, if any
+    pub link: Option<Cow<'a, str>>,
+}
+
+impl<'a> Span<'a> {
+    /// Creates a span drawn with the style of the widget displaying it.
+    pub fn raw<T>(content: T) -> Span<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Span {
+            content: content.into(),
+            style: Style::default(),
+            link: None,
+        }
+    }
+
+    pub fn styled<T>(content: T, style: Style) -> Span<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Span {
+            content: content.into(),
+            style,
+            link: None,
+        }
+    }
+
+    /// Makes the span a hyperlink to `target`, which terminals supporting OSC 8 hyperlinks open
+    /// when it is clicked.
+    ///
+    /// ```rust
+    /// # use tui::text::Span;
+    /// let span = Span::raw("docs").link("https://docs.rs/tui");
+    /// assert_eq!(span.link.as_deref(), Some("https://docs.rs/tui"));
+    /// ```
+    pub fn link<T>(mut self, target: T) -> Span<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.link = Some(target.into());
+        self
+    }
+
+    /// Width of the content in columns.
+    pub fn width(&self) -> usize {
+        self.content.width()
+    }
+}
+
+impl<'a, T> From<&'a T> for Span<'a>
+where
+    T: AsRef<str> + ?Sized,
+{
+    fn from(s: &'a T) -> Span<'a> {
+        Span::raw(s.as_ref())
+    }
+}
+
+impl<'a> From<String> for Span<'a> {
+    fn from(s: String) -> Span<'a> {
+        Span::raw(s)
+    }
+}
+
+impl<'a, 'b: 'a> From<&'a Span<'b>> for Span<'a> {
+    fn from(span: &'a Span<'b>) -> Span<'a> {
+        Span {
+            content: Cow::Borrowed(span.content.as_ref()),
+            style: span.style,
+            link: span.link.as_ref().map(|l| Cow::Borrowed(l.as_ref())),
+        }
+    }
+}
+
+/// A line of text made of spans with different styles.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Spans<'a>(pub Vec<Span<'a>>);
+
+impl<'a> Spans<'a> {
+    /// Width of the line in columns.
+    pub fn width(&self) -> usize {
+        self.0.iter().map(Span::width).sum()
+    }
+}
+
+impl<'a, T> From<&'a T> for Spans<'a>
+where
+    T: AsRef<str> + ?Sized,
+{
+    fn from(s: &'a T) -> Spans<'a> {
+        Spans(vec![Span::from(s)])
+    }
+}
+
+impl<'a> From<String> for Spans<'a> {
+    fn from(s: String) -> Spans<'a> {
+        Spans(vec![Span::from(s)])
+    }
+}
+
+impl<'a> From<Span<'a>> for Spans<'a> {
+    fn from(span: Span<'a>) -> Spans<'a> {
+        Spans(vec![span])
+    }
+}
+
+impl<'a> From<Vec<Span<'a>>> for Spans<'a> {
+    fn from(spans: Vec<Span<'a>>) -> Spans<'a> {
+        Spans(spans)
+    }
+}
+
+impl<'a, 'b: 'a> From<&'a Spans<'b>> for Spans<'a> {
+    fn from(spans: &'a Spans<'b>) -> Spans<'a> {
+        Spans(spans.0.iter().map(Span::from).collect())
+    }
+}
+
+/// Several lines of styled text.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Text<'a> {
+    pub lines: Vec<Spans<'a>>,
+}
+
+impl<'a> Text<'a> {
+    /// Creates a text drawn with the style of the widget displaying it, one line per `\n`.
+    pub fn raw<T>(content: T) -> Text<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Text::styled(content, Style::default())
+    }
+
+    /// Creates a text with a single style, one line per `\n`.
+    pub fn styled<T>(content: T, style: Style) -> Text<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let mut text = Text::default();
+        text.push(content.into(), style);
+        text.trim_last_line();
+        text
+    }
+
+    /// Width of the widest line in columns.
+    pub fn width(&self) -> usize {
+        self.lines.iter().map(Spans::width).max().unwrap_or(0)
+    }
+
+    /// Number of lines.
+    pub fn height(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Appends some content to the last line, starting a new line at each `\n`.
+    fn push(&mut self, content: Cow<'a, str>, style: Style) {
+        if self.lines.is_empty() {
+            self.lines.push(Spans::default());
+        }
+        let push_line = |lines: &mut Vec<Spans<'a>>, line: Cow<'a, str>, first: bool| {
+            if !first {
+                lines.push(Spans::default());
+            }
+            if !line.is_empty() {
+                lines.last_mut().unwrap().0.push(Span::styled(line, style));
+            }
+        };
+        match content {
+            Cow::Borrowed(s) => {
+                for (i, line) in s.split('\n').enumerate() {
+                    push_line(&mut self.lines, Cow::Borrowed(line), i == 0);
+                }
+            }
+            Cow::Owned(s) => {
+                for (i, line) in s.split('\n').enumerate() {
+                    push_line(&mut self.lines, Cow::Owned(line.to_string()), i == 0);
+                }
+            }
+        }
+    }
+
+    /// Drops the empty line following a final `\n`, as `str::lines` does.
+    fn trim_last_line(&mut self) {
+        if self.lines.len() > 1 && matches!(self.lines.last(), Some(line) if line.0.is_empty()) {
+            self.lines.pop();
+        }
+    }
+}
+
+impl<'a, T> From<&'a T> for Text<'a>
+where
+    T: AsRef<str> + ?Sized,
+{
+    fn from(s: &'a T) -> Text<'a> {
+        Text::raw(s.as_ref())
+    }
+}
+
+impl<'a> From<String> for Text<'a> {
+    fn from(s: String) -> Text<'a> {
+        Text::raw(s)
+    }
+}
+
+impl<'a> From<Span<'a>> for Text<'a> {
+    fn from(span: Span<'a>) -> Text<'a> {
+        Text {
+            lines: vec![Spans::from(span)],
+        }
+    }
+}
+
+impl<'a> From<Spans<'a>> for Text<'a> {
+    fn from(spans: Spans<'a>) -> Text<'a> {
+        Text { lines: vec![spans] }
+    }
+}
+
+impl<'a> From<Vec<Spans<'a>>> for Text<'a> {
+    fn from(lines: Vec<Spans<'a>>) -> Text<'a> {
+        Text { lines }
+    }
+}
+
+impl<'a> From<widgets::Text<'a>> for Span<'a> {
+    fn from(text: widgets::Text<'a>) -> Span<'a> {
+        match text {
+            widgets::Text::Raw(content) => Span::raw(content),
+            widgets::Text::Styled(content, style) => Span::styled(content, style),
+        }
+    }
+}
+
+impl<'a> From<widgets::Text<'a>> for Spans<'a> {
+    fn from(text: widgets::Text<'a>) -> Spans<'a> {
+        Spans(vec![Span::from(text)])
+    }
+}
+
+impl<'a> From<widgets::Text<'a>> for Text<'a> {
+    fn from(text: widgets::Text<'a>) -> Text<'a> {
+        match text {
+            widgets::Text::Raw(content) => Text::raw(content),
+            widgets::Text::Styled(content, style) => Text::styled(content, style),
+        }
+    }
+}
+
+/// Joins the fragments the way `Paragraph` used to display them, a new line starting at each
+/// `\n`.
+impl<'a, 'b: 'a> From<slice::Iter<'a, widgets::Text<'b>>> for Text<'a> {
+    fn from(fragments: slice::Iter<'a, widgets::Text<'b>>) -> Text<'a> {
+        fragments.collect()
+    }
+}
+
+impl<'a, 'b: 'a> std::iter::FromIterator<&'a widgets::Text<'b>> for Text<'a> {
+    fn from_iter<I>(fragments: I) -> Text<'a>
+    where
+        I: IntoIterator<Item = &'a widgets::Text<'b>>,
+    {
+        let mut text = Text::default();
+        for fragment in fragments {
+            match fragment {
+                widgets::Text::Raw(content) => {
+                    text.push(Cow::Borrowed(content.as_ref()), Style::default())
+                }
+                widgets::Text::Styled(content, style) => {
+                    text.push(Cow::Borrowed(content.as_ref()), *style)
+                }
+            }
+        }
+        text.trim_last_line();
+        text
+    }
+}
+
+impl<'a> Extend<Spans<'a>> for Text<'a> {
+    fn extend<T: IntoIterator<Item = Spans<'a>>>(&mut self, iter: T) {
+        self.lines.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn text_from_str_splits_lines() {
+        let text = Text::from("first\n\nthird\n");
+        assert_eq!(
+            text.lines,
+            vec![Spans::from("first"), Spans::default(), Spans::from("third")]
+        );
+        assert_eq!(text.width(), 5);
+        assert_eq!(Text::from("").height(), 1);
+    }
+
+    #[test]
+    fn text_from_fragments_joins_lines() {
+        let style = Style::default().fg(Color::Red);
+        let fragments = [
+            widgets::Text::raw("a "),
+            widgets::Text::styled("b\nc", style),
+            widgets::Text::raw(" d\n"),
+        ];
+        let text = Text::from(fragments.iter());
+        assert_eq!(
+            text.lines,
+            vec![
+                Spans(vec![Span::raw("a "), Span::styled("b", style)]),
+                Spans(vec![Span::styled("c", style), Span::raw(" d")]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! Default styles of the widgets.
+//!
+//! A [`Theme`] gives a style to each role a widget can draw: borders, titles, selected items,
+//! table headers... Widgets read the theme of the buffer they are rendered to and apply their own
+//! styles on top of it, so that a style given to a widget only overrides what it sets. The theme
+//! of a [`Terminal`](crate::Terminal) can be changed at any time with
+//! [`Terminal::set_theme`](crate::Terminal::set_theme), it is used from the next draw call.
+//!
+//! Themes can be loaded from a file with one `role = style` line per role, styles being written
+//! like the attributes of a [markup](crate::text::markup) tag. Empty lines and lines starting with
+//! `#` are ignored, and roles which are not listed keep the default style:
+//!
+//! ```rust
+//! # use tui::theme::Theme;
+//! # use tui::style::{Color, Style};
+//! let theme: Theme = "
+//!     text = fg=gray;bg=black
+//!     border = fg=dark_gray
+//!     title = fg=white;mod=bold
+//!     selection = fg=black;bg=light_blue
+//! "
+//! .parse()
+//! .unwrap();
+//! assert_eq!(theme.border, Style::default().fg(Color::DarkGray));
+//! assert_eq!(theme.header, Style::default());
+//! ```
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::style::Style;
+use crate::text::markup::{self, ErrorKind};
+
+/// The styles used by the widgets for each role, before their own styles are applied.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// Text and background of the content of the widgets
+    pub text: Style,
+    /// Area of a [`Block`](crate::widgets::Block)
+    pub block: Style,
+    /// Borders of a [`Block`](crate::widgets::Block)
+    pub border: Style,
+    /// Titles of blocks and of the axes of a [`Chart`](crate::widgets::Chart)
+    pub title: Style,
+    /// Highlighted item of a list, table, tabs or tree, and selected text of an input
+    pub selection: Style,
+    /// Header of a [`Table`](crate::widgets::Table)
+    pub header: Style,
+    /// Lines of the axes of a [`Chart`](crate::widgets::Chart)
+    pub axis: Style,
+    /// Labels of the axes of a chart and of the bars of a [`BarChart`](crate::widgets::BarChart)
+    pub label: Style,
+    /// Values of the bars of a [`BarChart`](crate::widgets::BarChart)
+    pub value: Style,
+    /// Bars of a [`BarChart`](crate::widgets::BarChart), a [`Gauge`](crate::widgets::Gauge) or a
+    /// [`Sparkline`](crate::widgets::Sparkline)
+    pub bar: Style,
+    /// Guides of a [`Tree`](crate::widgets::Tree)
+    pub guide: Style,
+    /// Track of a [`Scrollbar`](crate::widgets::Scrollbar)
+    pub scrollbar: Style,
+    /// Thumb of a [`Scrollbar`](crate::widgets::Scrollbar)
+    pub scrollbar_thumb: Style,
+}
+
+impl Theme {
+    /// Loads a theme from a file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Theme, Error> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    fn role_mut(&mut self, role: &str) -> Option<&mut Style> {
+        let style = match role {
+            "text" => &mut self.text,
+            "block" => &mut self.block,
+            "border" => &mut self.border,
+            "title" => &mut self.title,
+            "selection" => &mut self.selection,
+            "header" => &mut self.header,
+            "axis" => &mut self.axis,
+            "label" => &mut self.label,
+            "value" => &mut self.value,
+            "bar" => &mut self.bar,
+            "guide" => &mut self.guide,
+            "scrollbar" => &mut self.scrollbar,
+            "scrollbar_thumb" => &mut self.scrollbar_thumb,
+            _ => return None,
+        };
+        Some(style)
+    }
+}
+
+impl FromStr for Theme {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Theme, Error> {
+        let mut theme = Theme::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.splitn(2, '=');
+            let (role, value) = match (parts.next(), parts.next()) {
+                (Some(role), Some(value)) => (role.trim(), value.trim()),
+                _ => return Err(Error::InvalidLine(i + 1)),
+            };
+            let style = theme.role_mut(role).ok_or_else(|| Error::UnknownRole {
+                line: i + 1,
+                role: role.to_owned(),
+            })?;
+            if !value.is_empty() {
+                *style = markup::parse_style(value)
+                    .map_err(|kind| Error::InvalidStyle { line: i + 1, kind })?;
+            }
+        }
+        Ok(theme)
+    }
+}
+
+/// An error while loading a theme. Lines start at 1.
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// A line is not a `role = style` pair
+    InvalidLine(usize),
+    UnknownRole {
+        line: usize,
+        role: String,
+    },
+    InvalidStyle {
+        line: usize,
+        kind: ErrorKind,
+    },
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::Io(err)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Io(ref err) => write!(f, "{}", err),
+            Error::InvalidLine(line) => write!(f, "line {}: expected `role = style`", line),
+            Error::UnknownRole { line, ref role } => {
+                write!(f, "line {}: unknown role `{}`", line, role)
+            }
+            Error::InvalidStyle { line, ref kind } => write!(f, "line {}: {}", line, kind),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Error::Io(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
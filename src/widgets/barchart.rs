@@ -20,7 +20,7 @@ use unicode_width::UnicodeWidthStr;
 ///     .bar_width(3)
 ///     .bar_gap(1)
 ///     .style(Style::default().fg(Color::Yellow).bg(Color::Red))
-///     .value_style(Style::default().fg(Color::Red).modifier(Modifier::BOLD))
+///     .value_style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
 ///     .label_style(Style::default().fg(Color::White))
 ///     .data(&[("B0", 0), ("B1", 2), ("B2", 4), ("B3", 3)])
 ///     .max(4);
@@ -120,9 +120,14 @@ impl<'a> BarChart<'a> {
 
 impl<'a> Widget for BarChart<'a> {
     fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let theme = *buf.theme();
+        self.style = theme.bar.patch(self.style);
+        self.value_style = theme.value.patch(self.value_style);
+        self.label_style = theme.label.patch(self.label_style);
+
         let chart_area = match self.block {
-            Some(ref mut b) => {
-                b.render(area, buf);
+            Some(ref b) => {
+                b.clone().render(area, buf);
                 b.inner(area)
             }
             None => area,
@@ -132,7 +137,7 @@ impl<'a> Widget for BarChart<'a> {
             return;
         }
 
-        buf.set_background(chart_area, self.style.bg);
+        buf.set_style(chart_area, self.style);
 
         let max = self
             .max
@@ -2,7 +2,10 @@ use crate::buffer::Buffer;
 use crate::layout::Rect;
 use crate::style::Style;
 use crate::symbols::line;
-use crate::widgets::{Borders, Widget};
+use crate::text::Spans;
+use crate::widgets::{
+    Borders, Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget, Widget,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub enum BorderType {
@@ -39,10 +42,10 @@ impl BorderType {
 ///     .border_type(BorderType::Rounded)
 ///     .style(Style::default().bg(Color::Black));
 /// ```
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Block<'a> {
     /// Optional title place on the upper left of the block
-    title: Option<&'a str>,
+    title: Option<Spans<'a>>,
     /// Title style
     title_style: Style,
     /// Visible borders
@@ -54,6 +57,8 @@ pub struct Block<'a> {
     border_type: BorderType,
     /// Widget style
     style: Style,
+    /// Scrollbar drawn inside the right (vertical) or bottom (horizontal) border
+    scrollbar: Option<Scrollbar>,
 }
 
 impl<'a> Default for Block<'a> {
@@ -65,13 +70,19 @@ impl<'a> Default for Block<'a> {
             border_style: Default::default(),
             border_type: BorderType::Plain,
             style: Default::default(),
+            scrollbar: None,
         }
     }
 }
 
 impl<'a> Block<'a> {
-    pub fn title(mut self, title: &'a str) -> Block<'a> {
-        self.title = Some(title);
+    /// Sets the title, which can mix several styles. The style of each part is applied on top of
+    /// the title style.
+    pub fn title<T>(mut self, title: T) -> Block<'a>
+    where
+        T: Into<Spans<'a>>,
+    {
+        self.title = Some(title.into());
         self
     }
 
@@ -100,6 +111,67 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Draws a scrollbar inside the right border (vertical) or the bottom border (horizontal)
+    /// of the block. A column or a row is reserved for it when that border is not visible.
+    ///
+    /// The scrollbar is drawn by the wrapped widget ([`List`], [`Table`] and [`Paragraph`]) once
+    /// it knows its scrolling position, see [`Block::render_scrollbar`].
+    ///
+    /// [`List`]: crate::widgets::List
+    /// [`Table`]: crate::widgets::Table
+    /// [`Paragraph`]: crate::widgets::Paragraph
+    pub fn scrollbar(mut self, scrollbar: Scrollbar) -> Block<'a> {
+        self.scrollbar = Some(scrollbar);
+        self
+    }
+
+    /// Whether the block draws a scrollbar.
+    pub(crate) fn has_scrollbar(&self) -> bool {
+        self.scrollbar.is_some()
+    }
+
+    /// Draws the scrollbar of the block, if any, for the given state. `area` is the area the
+    /// block itself was rendered to.
+    pub fn render_scrollbar(&self, area: Rect, buf: &mut Buffer, state: &ScrollbarState) {
+        let scrollbar = match self.scrollbar {
+            Some(scrollbar) => scrollbar,
+            None => return,
+        };
+        if area.width < 2 || area.height < 2 {
+            return;
+        }
+        let track = match scrollbar.orientation() {
+            ScrollbarOrientation::Vertical => {
+                let top = if self.borders.intersects(Borders::TOP) || self.title.is_some() {
+                    area.top() + 1
+                } else {
+                    area.top()
+                };
+                let bottom = if self.borders.intersects(Borders::BOTTOM) {
+                    area.bottom() - 1
+                } else {
+                    area.bottom()
+                };
+                Rect::new(area.right() - 1, top, 1, bottom - top)
+            }
+            ScrollbarOrientation::Horizontal => {
+                let left = if self.borders.intersects(Borders::LEFT) {
+                    area.left() + 1
+                } else {
+                    area.left()
+                };
+                let right = if self.borders.intersects(Borders::RIGHT) {
+                    area.right() - 1
+                } else {
+                    area.right()
+                };
+                Rect::new(left, area.bottom() - 1, right - left, 1)
+            }
+        };
+        let mut state = *state;
+        scrollbar.render(track, buf, &mut state);
+    }
+
     /// Compute the inner area of a block based on its border visibility rules.
     pub fn inner(&self, area: Rect) -> Rect {
         if area.width < 2 || area.height < 2 {
@@ -114,10 +186,15 @@ impl<'a> Block<'a> {
             inner.y += 1;
             inner.height -= 1;
         }
-        if self.borders.intersects(Borders::RIGHT) {
+        let scrollbar = self.scrollbar.map(|s| s.orientation());
+        if self.borders.intersects(Borders::RIGHT)
+            || scrollbar == Some(ScrollbarOrientation::Vertical)
+        {
             inner.width -= 1;
         }
-        if self.borders.intersects(Borders::BOTTOM) {
+        if self.borders.intersects(Borders::BOTTOM)
+            || scrollbar == Some(ScrollbarOrientation::Horizontal)
+        {
             inner.height -= 1;
         }
         inner
@@ -125,12 +202,17 @@ impl<'a> Block<'a> {
 }
 
 impl<'a> Widget for Block<'a> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let theme = *buf.theme();
+        self.style = theme.block.patch(self.style);
+        self.border_style = theme.border.patch(self.border_style);
+        self.title_style = theme.title.patch(self.title_style);
+
         if area.width < 2 || area.height < 2 {
             return;
         }
 
-        buf.set_background(area, self.style.bg);
+        buf.set_style(area, self.style);
 
         let symbols = BorderType::line_symbols(self.border_type);
         // Sides
@@ -187,7 +269,7 @@ impl<'a> Widget for Block<'a> {
                 .set_style(self.border_style);
         }
 
-        if let Some(title) = self.title {
+        if let Some(ref title) = self.title {
             let lx = if self.borders.intersects(Borders::LEFT) {
                 1
             } else {
@@ -199,13 +281,7 @@ impl<'a> Widget for Block<'a> {
                 0
             };
             let width = area.width - lx - rx;
-            buf.set_stringn(
-                area.left() + lx,
-                area.top(),
-                title,
-                width as usize,
-                self.title_style,
-            );
+            buf.set_spans(area.left() + lx, area.top(), title, width, self.title_style);
         }
     }
 }
@@ -415,10 +415,10 @@ impl<'a, F> Widget for Canvas<'a, F>
 where
     F: Fn(&mut Context),
 {
-    fn render(mut self, area: Rect, buf: &mut Buffer) {
+    fn render(self, area: Rect, buf: &mut Buffer) {
         let canvas_area = match self.block {
-            Some(ref mut b) => {
-                b.render(area, buf);
+            Some(ref b) => {
+                b.clone().render(area, buf);
                 b.inner(area)
             }
             None => area,
@@ -1,8 +1,9 @@
 use crate::{
     buffer::Buffer,
     layout::{Constraint, Rect},
-    style::Style,
+    style::{Color, Style},
     symbols,
+    text::{Span, Spans},
     widgets::{
         canvas::{Canvas, Line, Points},
         Block, Borders, Widget,
@@ -15,15 +16,16 @@ use unicode_width::UnicodeWidthStr;
 #[derive(Debug, Clone)]
 pub struct Axis<'a, L>
 where
-    L: AsRef<str> + 'a,
+    L: 'a,
 {
     /// Title displayed next to axis end
-    title: Option<&'a str>,
+    title: Option<Spans<'a>>,
     /// Style of the title
     title_style: Style,
     /// Bounds for the axis (all data points outside these limits will not be represented)
     bounds: [f64; 2],
-    /// A list of labels to put to the left or below the axis
+    /// A list of labels to put to the left or below the axis. A reference to a label converts
+    /// into a [`Span`], labels can be strings or styled spans.
     labels: Option<&'a [L]>,
     /// The labels' style
     labels_style: Style,
@@ -33,7 +35,7 @@ where
 
 impl<'a, L> Default for Axis<'a, L>
 where
-    L: AsRef<str>,
+    L: 'a,
 {
     fn default() -> Axis<'a, L> {
         Axis {
@@ -49,10 +51,14 @@ where
 
 impl<'a, L> Axis<'a, L>
 where
-    L: AsRef<str>,
+    L: 'a,
+    Span<'a>: From<&'a L>,
 {
-    pub fn title(mut self, title: &'a str) -> Axis<'a, L> {
-        self.title = Some(title);
+    pub fn title<T>(mut self, title: T) -> Axis<'a, L>
+    where
+        T: Into<Spans<'a>>,
+    {
+        self.title = Some(title.into());
         self
     }
 
@@ -223,8 +229,8 @@ impl Default for ChartLayout {
 #[derive(Debug, Clone)]
 pub struct Chart<'a, LX, LY>
 where
-    LX: AsRef<str> + 'a,
-    LY: AsRef<str> + 'a,
+    LX: 'a,
+    LY: 'a,
 {
     /// A block to display around the widget eventually
     block: Option<Block<'a>>,
@@ -243,8 +249,8 @@ where
 
 impl<'a, LX, LY> Default for Chart<'a, LX, LY>
 where
-    LX: AsRef<str>,
-    LY: AsRef<str>,
+    LX: 'a,
+    LY: 'a,
 {
     fn default() -> Chart<'a, LX, LY> {
         Chart {
@@ -260,8 +266,10 @@ where
 
 impl<'a, LX, LY> Chart<'a, LX, LY>
 where
-    LX: AsRef<str>,
-    LY: AsRef<str>,
+    LX: 'a,
+    LY: 'a,
+    Span<'a>: From<&'a LX>,
+    Span<'a>: From<&'a LY>,
 {
     pub fn block(mut self, block: Block<'a>) -> Chart<'a, LX, LY> {
         self.block = Some(block);
@@ -331,11 +339,11 @@ where
         if let Some(y_labels) = self.y_axis.labels {
             let mut max_width = y_labels
                 .iter()
-                .fold(0, |acc, l| max(l.as_ref().width(), acc))
+                .fold(0, |acc, l| max(Span::from(l).width(), acc))
                 as u16;
             if let Some(x_labels) = self.x_axis.labels {
                 if !x_labels.is_empty() {
-                    max_width = max(max_width, x_labels[0].as_ref().width() as u16);
+                    max_width = max(max_width, Span::from(&x_labels[0]).width() as u16);
                 }
             }
             if x + max_width < area.right() {
@@ -358,14 +366,14 @@ where
             layout.graph_area = Rect::new(x, area.top(), area.right() - x, y - area.top() + 1);
         }
 
-        if let Some(title) = self.x_axis.title {
+        if let Some(ref title) = self.x_axis.title {
             let w = title.width() as u16;
             if w < layout.graph_area.width && layout.graph_area.height > 2 {
                 layout.title_x = Some((x + layout.graph_area.width - w, y));
             }
         }
 
-        if let Some(title) = self.y_axis.title {
+        if let Some(ref title) = self.y_axis.title {
             let w = title.width() as u16;
             if w + 1 < layout.graph_area.width && layout.graph_area.height > 2 {
                 layout.title_y = Some((x + 1, area.top()));
@@ -401,13 +409,24 @@ where
 
 impl<'a, LX, LY> Widget for Chart<'a, LX, LY>
 where
-    LX: AsRef<str>,
-    LY: AsRef<str>,
+    LX: 'a,
+    LY: 'a,
+    Span<'a>: From<&'a LX>,
+    Span<'a>: From<&'a LY>,
 {
     fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let theme = *buf.theme();
+        self.style = theme.text.patch(self.style);
+        self.x_axis.style = theme.axis.patch(self.x_axis.style);
+        self.x_axis.labels_style = theme.label.patch(self.x_axis.labels_style);
+        self.x_axis.title_style = theme.title.patch(self.x_axis.title_style);
+        self.y_axis.style = theme.axis.patch(self.y_axis.style);
+        self.y_axis.labels_style = theme.label.patch(self.y_axis.labels_style);
+        self.y_axis.title_style = theme.title.patch(self.y_axis.title_style);
+
         let chart_area = match self.block {
-            Some(ref mut b) => {
-                b.render(area, buf);
+            Some(ref b) => {
+                b.clone().render(area, buf);
                 b.inner(area)
             }
             None => area,
@@ -419,31 +438,33 @@ where
             return;
         }
 
-        buf.set_background(chart_area, self.style.bg);
+        buf.set_style(chart_area, self.style);
 
         if let Some((x, y)) = layout.title_x {
-            let title = self.x_axis.title.unwrap();
-            buf.set_string(x, y, title, self.x_axis.title_style);
+            if let Some(ref title) = self.x_axis.title {
+                let width = graph_area.right().saturating_sub(x);
+                buf.set_spans(x, y, title, width, self.x_axis.title_style);
+            }
         }
 
         if let Some((x, y)) = layout.title_y {
-            let title = self.y_axis.title.unwrap();
-            buf.set_string(x, y, title, self.y_axis.title_style);
+            if let Some(ref title) = self.y_axis.title {
+                let width = graph_area.right().saturating_sub(x);
+                buf.set_spans(x, y, title, width, self.y_axis.title_style);
+            }
         }
 
         if let Some(y) = layout.label_x {
             let labels = self.x_axis.labels.unwrap();
-            let total_width = labels.iter().fold(0, |acc, l| l.as_ref().width() + acc) as u16;
+            let total_width = labels.iter().fold(0, |acc, l| Span::from(l).width() + acc) as u16;
             let labels_len = labels.len() as u16;
             if total_width < graph_area.width && labels_len > 1 {
                 for (i, label) in labels.iter().enumerate() {
-                    buf.set_string(
-                        graph_area.left() + i as u16 * (graph_area.width - 1) / (labels_len - 1)
-                            - label.as_ref().width() as u16,
-                        y,
-                        label.as_ref(),
-                        self.x_axis.labels_style,
-                    );
+                    let label = Span::from(label);
+                    let x = graph_area.left()
+                        + i as u16 * (graph_area.width - 1) / (labels_len - 1)
+                        - label.width() as u16;
+                    buf.set_span(x, y, &label, label.width() as u16, self.x_axis.labels_style);
                 }
             }
         }
@@ -454,12 +475,9 @@ where
             for (i, label) in labels.iter().enumerate() {
                 let dy = i as u16 * (graph_area.height - 1) / (labels_len - 1);
                 if dy < graph_area.bottom() {
-                    buf.set_string(
-                        x,
-                        graph_area.bottom() - 1 - dy,
-                        label.as_ref(),
-                        self.y_axis.labels_style,
-                    );
+                    let label = Span::from(label);
+                    let y = graph_area.bottom() - 1 - dy;
+                    buf.set_span(x, y, &label, label.width() as u16, self.y_axis.labels_style);
                 }
             }
         }
@@ -490,14 +508,14 @@ where
 
         for dataset in self.datasets {
             Canvas::default()
-                .background_color(self.style.bg)
+                .background_color(self.style.bg.unwrap_or(Color::Reset))
                 .x_bounds(self.x_axis.bounds)
                 .y_bounds(self.y_axis.bounds)
                 .marker(dataset.marker)
                 .paint(|ctx| {
                     ctx.draw(&Points {
                         coords: dataset.data,
-                        color: dataset.style.fg,
+                        color: dataset.style.fg.unwrap_or(Color::Reset),
                     });
                     if let GraphType::Line = dataset.graph_type {
                         for data in dataset.data.windows(2) {
@@ -506,7 +524,7 @@ where
                                 y1: data[0].1,
                                 x2: data[1].0,
                                 y2: data[1].1,
-                                color: dataset.style.fg,
+                                color: dataset.style.fg.unwrap_or(Color::Reset),
                             })
                         }
                     }
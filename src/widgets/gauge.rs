@@ -14,7 +14,7 @@ use crate::widgets::{Block, Widget};
 /// # use tui::style::{Style, Color, Modifier};
 /// Gauge::default()
 ///     .block(Block::default().borders(Borders::ALL).title("Progress"))
-///     .style(Style::default().fg(Color::White).bg(Color::Black).modifier(Modifier::ITALIC))
+///     .style(Style::default().fg(Color::White).bg(Color::Black).add_modifier(Modifier::ITALIC))
 ///     .percent(20);
 /// ```
 #[derive(Debug, Clone)]
@@ -74,9 +74,12 @@ impl<'a> Gauge<'a> {
 
 impl<'a> Widget for Gauge<'a> {
     fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let theme = *buf.theme();
+        self.style = theme.bar.patch(self.style);
+
         let gauge_area = match self.block {
-            Some(ref mut b) => {
-                b.render(area, buf);
+            Some(ref b) => {
+                b.clone().render(area, buf);
                 b.inner(area)
             }
             None => area,
@@ -85,9 +88,7 @@ impl<'a> Widget for Gauge<'a> {
             return;
         }
 
-        if self.style.bg != Color::Reset {
-            buf.set_background(gauge_area, self.style.bg);
-        }
+        buf.set_style(gauge_area, self.style);
 
         let center = gauge_area.height / 2 + gauge_area.top();
         let width = (f64::from(gauge_area.width) * self.ratio).round() as u16;
@@ -110,8 +111,8 @@ impl<'a> Widget for Gauge<'a> {
             // Fix colors
             for x in gauge_area.left()..end {
                 buf.get_mut(x, y)
-                    .set_fg(self.style.bg)
-                    .set_bg(self.style.fg);
+                    .set_fg(self.style.bg.unwrap_or(Color::Reset))
+                    .set_bg(self.style.fg.unwrap_or(Color::Reset));
             }
         }
     }
@@ -0,0 +1,370 @@
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::buffer::Buffer;
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::layout::Rect;
+use crate::style::Style;
+use crate::widgets::{Block, StatefulWidget, Widget};
+
+/// Content and editing state of an [`Input`].
+///
+/// The cursor always sits on a grapheme boundary so that multi-codepoint characters are edited
+/// as a whole. Positions are byte offsets in [`InputState::value`].
+#[derive(Debug, Clone, Default)]
+pub struct InputState {
+    value: String,
+    /// Byte offset of the cursor
+    cursor: usize,
+    /// Byte offset of the other end of the selection, if any
+    anchor: Option<usize>,
+    /// Number of columns scrolled out on the left during the last render
+    offset: usize,
+    /// Character displayed in place of each grapheme
+    mask: Option<char>,
+    /// Position of the cursor on the screen during the last render
+    cursor_position: Option<(u16, u16)>,
+}
+
+impl InputState {
+    /// Creates a state holding the given value, with the cursor at its end.
+    pub fn new<S>(value: S) -> InputState
+    where
+        S: Into<String>,
+    {
+        let mut state = InputState::default();
+        state.set_value(value);
+        state
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the value, moves the cursor to its end and clears the selection.
+    pub fn set_value<S>(&mut self, value: S)
+    where
+        S: Into<String>,
+    {
+        self.value = value.into();
+        self.cursor = self.value.len();
+        self.anchor = None;
+    }
+
+    /// Takes the value out of the state, leaving it empty.
+    pub fn take_value(&mut self) -> String {
+        self.cursor = 0;
+        self.anchor = None;
+        self.offset = 0;
+        std::mem::take(&mut self.value)
+    }
+
+    pub fn mask(&self) -> Option<char> {
+        self.mask
+    }
+
+    /// Displays every grapheme as the given character, e.g. `Some('*')` for passwords.
+    pub fn set_mask(&mut self, mask: Option<char>) {
+        self.mask = mask;
+    }
+
+    /// Byte offset of the cursor in the value.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Where the terminal cursor should be placed (see [`Terminal::set_cursor`]) according to
+    /// the last render. `None` if the input was not visible.
+    ///
+    /// [`Terminal::set_cursor`]: crate::Terminal::set_cursor
+    pub fn cursor_position(&self) -> Option<(u16, u16)> {
+        self.cursor_position
+    }
+
+    /// Byte range of the selected text, if any.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        match self.anchor {
+            Some(anchor) if anchor < self.cursor => Some(anchor..self.cursor),
+            Some(anchor) if anchor > self.cursor => Some(self.cursor..anchor),
+            _ => None,
+        }
+    }
+
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection().map(|range| &self.value[range])
+    }
+
+    pub fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.cursor = self.value.len();
+    }
+
+    /// Inserts a character at the cursor, replacing the selection if any.
+    pub fn insert_char(&mut self, c: char) {
+        self.delete_selection();
+        self.value.insert(self.cursor, c);
+        self.cursor += c.len_utf8();
+    }
+
+    /// Inserts a string at the cursor, replacing the selection if any.
+    pub fn insert_str(&mut self, s: &str) {
+        self.delete_selection();
+        self.value.insert_str(self.cursor, s);
+        self.cursor += s.len();
+    }
+
+    /// Deletes the selection, or the grapheme before the cursor.
+    pub fn delete_backward(&mut self) {
+        if !self.delete_selection() {
+            let start = self.prev_boundary(self.cursor);
+            self.value.replace_range(start..self.cursor, "");
+            self.cursor = start;
+        }
+    }
+
+    /// Deletes the selection, or the grapheme after the cursor.
+    pub fn delete_forward(&mut self) {
+        if !self.delete_selection() {
+            let end = self.next_boundary(self.cursor);
+            self.value.replace_range(self.cursor..end, "");
+        }
+    }
+
+    /// Moves the cursor one grapheme to the left. The selection is extended if `select` is true,
+    /// otherwise the cursor moves to its start.
+    pub fn move_left(&mut self, select: bool) {
+        let position = match self.selection() {
+            Some(range) if !select => range.start,
+            _ => self.prev_boundary(self.cursor),
+        };
+        self.move_to(position, select);
+    }
+
+    /// Moves the cursor one grapheme to the right. The selection is extended if `select` is
+    /// true, otherwise the cursor moves to its end.
+    pub fn move_right(&mut self, select: bool) {
+        let position = match self.selection() {
+            Some(range) if !select => range.end,
+            _ => self.next_boundary(self.cursor),
+        };
+        self.move_to(position, select);
+    }
+
+    /// Moves the cursor to the start of the current or previous word.
+    pub fn move_word_left(&mut self, select: bool) {
+        let mut position = self.cursor;
+        let mut in_word = false;
+        for (i, g) in self.value[..self.cursor].grapheme_indices(true).rev() {
+            let is_space = g.chars().all(char::is_whitespace);
+            if in_word && is_space {
+                break;
+            }
+            in_word |= !is_space;
+            position = i;
+        }
+        self.move_to(position, select);
+    }
+
+    /// Moves the cursor to the end of the current or next word.
+    pub fn move_word_right(&mut self, select: bool) {
+        let mut position = self.cursor;
+        let mut in_word = false;
+        for (i, g) in self.value[self.cursor..].grapheme_indices(true) {
+            let is_space = g.chars().all(char::is_whitespace);
+            if in_word && is_space {
+                break;
+            }
+            in_word |= !is_space;
+            position = self.cursor + i + g.len();
+        }
+        self.move_to(position, select);
+    }
+
+    pub fn move_home(&mut self, select: bool) {
+        self.move_to(0, select);
+    }
+
+    pub fn move_end(&mut self, select: bool) {
+        self.move_to(self.value.len(), select);
+    }
+
+    /// Applies the usual line editing bindings: characters are inserted, `Backspace` and
+    /// `Delete` remove text, arrows, `Home` and `End` move the cursor (by words with `Control`,
+    /// extending the selection with `Shift`). Returns whether the key was handled.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        let select = key.modifiers.contains(KeyModifiers::SHIFT);
+        let word = key.modifiers.contains(KeyModifiers::CONTROL);
+        let plain = !key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        match key.code {
+            KeyCode::Char(c) if plain => self.insert_char(c),
+            KeyCode::Backspace => self.delete_backward(),
+            KeyCode::Delete => self.delete_forward(),
+            KeyCode::Left if word => self.move_word_left(select),
+            KeyCode::Left => self.move_left(select),
+            KeyCode::Right if word => self.move_word_right(select),
+            KeyCode::Right => self.move_right(select),
+            KeyCode::Home => self.move_home(select),
+            KeyCode::End => self.move_end(select),
+            _ => return false,
+        }
+        true
+    }
+
+    fn move_to(&mut self, position: usize, select: bool) {
+        if select {
+            if self.anchor.is_none() {
+                self.anchor = Some(self.cursor);
+            }
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = position;
+    }
+
+    /// Deletes the selected text. Returns false if nothing was selected.
+    fn delete_selection(&mut self) -> bool {
+        let range = match self.selection() {
+            Some(range) => range,
+            None => {
+                self.anchor = None;
+                return false;
+            }
+        };
+        self.cursor = range.start;
+        self.anchor = None;
+        self.value.replace_range(range, "");
+        true
+    }
+
+    fn prev_boundary(&self, position: usize) -> usize {
+        self.value[..position]
+            .grapheme_indices(true)
+            .next_back()
+            .map_or(0, |(i, _)| i)
+    }
+
+    fn next_boundary(&self, position: usize) -> usize {
+        self.value[position..]
+            .graphemes(true)
+            .next()
+            .map_or(position, |g| position + g.len())
+    }
+}
+
+/// A single line text input. The text scrolls horizontally to keep the cursor visible.
+///
+/// The widget does not draw the cursor itself, place the terminal cursor at
+/// [`InputState::cursor_position`] after drawing instead.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Block, Borders, Input};
+/// # use tui::style::{Style, Color};
+/// Input::default()
+///     .block(Block::default().title("Input").borders(Borders::ALL))
+///     .style(Style::default().fg(Color::Yellow))
+///     .selection_style(Style::default().bg(Color::Blue));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Input<'a> {
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Style of the text
+    style: Style,
+    /// Style of the selected text
+    selection_style: Style,
+}
+
+impl<'a> Input<'a> {
+    pub fn block(mut self, block: Block<'a>) -> Input<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Input<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn selection_style(mut self, style: Style) -> Input<'a> {
+        self.selection_style = style;
+        self
+    }
+}
+
+impl<'a> StatefulWidget for Input<'a> {
+    type State = InputState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let theme = *buf.theme();
+        self.style = theme.text.patch(self.style);
+        self.selection_style = theme.selection.patch(self.selection_style);
+
+        let input_area = match self.block {
+            Some(ref b) => {
+                b.clone().render(area, buf);
+                b.inner(area)
+            }
+            None => area,
+        };
+
+        state.cursor_position = None;
+        if input_area.width < 1 || input_area.height < 1 {
+            return;
+        }
+
+        buf.set_style(input_area, self.style);
+
+        let mask = state.mask.map(|c| c.to_string());
+        let graphemes = state
+            .value
+            .grapheme_indices(true)
+            .map(|(i, g)| {
+                let symbol = mask.as_ref().map_or(g, String::as_str);
+                (i, symbol, symbol.width())
+            })
+            .collect::<Vec<_>>();
+
+        // Scroll to keep the cursor visible, with room for it after the last grapheme
+        let width = input_area.width as usize;
+        let text_width: usize = graphemes.iter().map(|&(_, _, w)| w).sum();
+        let cursor_column: usize = graphemes
+            .iter()
+            .take_while(|&&(i, _, _)| i < state.cursor)
+            .map(|&(_, _, w)| w)
+            .sum();
+        state.offset = state.offset.min((text_width + 1).saturating_sub(width));
+        if cursor_column < state.offset {
+            state.offset = cursor_column;
+        } else if cursor_column >= state.offset + width {
+            state.offset = cursor_column + 1 - width;
+        }
+
+        let selection = state.selection();
+        let y = input_area.top();
+        let mut column = 0;
+        for (i, symbol, w) in graphemes {
+            // Graphemes cut by the edges of the area are not drawn
+            if w > 0 && column >= state.offset && column + w <= state.offset + width {
+                let x = input_area.left() + (column - state.offset) as u16;
+                let style = match selection {
+                    Some(ref range) if range.contains(&i) => self.selection_style,
+                    _ => self.style,
+                };
+                buf.get_mut(x, y).set_symbol(symbol).set_style(style);
+                for x in x + 1..x + w as u16 {
+                    buf.get_mut(x, y).reset();
+                }
+            }
+            column += w;
+        }
+
+        state.cursor_position =
+            Some((input_area.left() + (cursor_column - state.offset) as u16, y));
+    }
+}
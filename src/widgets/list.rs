@@ -3,14 +3,22 @@ use std::iter::{self, Iterator};
 use unicode_width::UnicodeWidthStr;
 
 use crate::buffer::Buffer;
+use crate::event::{MouseButton, MouseEvent, MouseEventKind};
 use crate::layout::{Corner, Rect};
 use crate::style::Style;
-use crate::widgets::{Block, StatefulWidget, Text, Widget};
+use crate::text;
+use crate::widgets::{exact_len, Block, ScrollbarState, StatefulWidget, Widget};
 
 #[derive(Debug, Clone)]
 pub struct ListState {
     offset: usize,
     selected: Option<usize>,
+    /// Area the items were drawn to during the last render
+    area: Rect,
+    /// Corner the items were drawn from during the last render
+    start_corner: Corner,
+    /// Number of items during the last render
+    len: usize,
 }
 
 impl Default for ListState {
@@ -18,6 +26,9 @@ impl Default for ListState {
         ListState {
             offset: 0,
             selected: None,
+            area: Rect::default(),
+            start_corner: Corner::TopLeft,
+            len: 0,
         }
     }
 }
@@ -33,26 +44,126 @@ impl ListState {
             self.offset = 0;
         }
     }
+
+    /// Index of the first visible item.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the index of the item displayed at the given position of the screen during the
+    /// last render, if any. The whole row of an item counts, including its highlight symbol.
+    pub fn item_at(&self, x: u16, y: u16) -> Option<usize> {
+        if !self.area.contains(x, y) {
+            return None;
+        }
+        let row = match self.start_corner {
+            Corner::BottomLeft => self.area.bottom() - 1 - y,
+            _ => y - self.area.top(),
+        };
+        let index = self.offset + row as usize;
+        if index < self.len {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// Scrolls the list by `n` items towards its first item. The selected item, if any, is
+    /// moved so that it stays visible.
+    pub fn scroll_up(&mut self, n: usize) {
+        self.offset = self.offset.saturating_sub(n);
+        self.keep_selection_visible();
+    }
+
+    /// Scrolls the list by `n` items towards its last item. The selected item, if any, is moved
+    /// so that it stays visible.
+    pub fn scroll_down(&mut self, n: usize) {
+        let max_offset = self.len.saturating_sub(self.area.height as usize);
+        self.offset = max_offset.min(self.offset + n);
+        self.keep_selection_visible();
+    }
+
+    /// Selects the item under a left click and scrolls the list with the mouse wheel, based on
+    /// the geometry of the last render. Returns whether the state changed.
+    pub fn handle_mouse_event(&mut self, event: &MouseEvent) -> bool {
+        if !self.area.contains(event.column, event.row) {
+            return false;
+        }
+        let (offset, selected) = (self.offset, self.selected);
+        // Items are stacked upwards when starting from the bottom
+        let reversed = self.start_corner == Corner::BottomLeft;
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(index) = self.item_at(event.column, event.row) {
+                    self.selected = Some(index);
+                }
+            }
+            MouseEventKind::ScrollUp if reversed => self.scroll_down(1),
+            MouseEventKind::ScrollUp => self.scroll_up(1),
+            MouseEventKind::ScrollDown if reversed => self.scroll_up(1),
+            MouseEventKind::ScrollDown => self.scroll_down(1),
+            _ => {}
+        }
+        offset != self.offset || selected != self.selected
+    }
+
+    /// Scrolling position of the last render, to be displayed by a
+    /// [`Scrollbar`](crate::widgets::Scrollbar).
+    pub fn scrollbar_state(&self) -> ScrollbarState {
+        ScrollbarState {
+            content_length: self.len,
+            position: self.offset,
+            viewport_length: self.area.height as usize,
+        }
+    }
+
+    fn keep_selection_visible(&mut self) {
+        let height = self.area.height as usize;
+        if height == 0 {
+            return;
+        }
+        if let Some(selected) = self.selected {
+            if selected < self.offset {
+                self.selected = Some(self.offset);
+            } else if selected >= self.offset + height {
+                self.selected = Some(self.offset + height - 1);
+            }
+        }
+    }
 }
 
 /// A widget to display several items among which one can be selected (optional)
 ///
+/// Items are anything converting into a [`text::Text`](crate::text::Text), such as strings,
+/// [`Text`] fragments or [`Spans`](crate::text::Spans) mixing several styles. Only the first
+/// line of each item is displayed.
+///
+/// The items are only iterated up to the last visible one. The number of items, used to scroll
+/// the list with the mouse and to draw a scrollbar, is known from iterators of known size such as
+/// `vec.iter().map(..)`, and should be given with [`List::item_count`] for the other ones.
+///
+/// [`Text`]: crate::widgets::Text
+///
 /// # Examples
 ///
 /// ```
-/// # use tui::widgets::{Block, Borders, List, Text};
+/// # use tui::widgets::{Block, Borders, List};
 /// # use tui::style::{Style, Color, Modifier};
-/// let items = ["Item 1", "Item 2", "Item 3"].iter().map(|i| Text::raw(*i));
+/// # use tui::text::{Span, Spans};
+/// let items = ["Item 1", "Item 2", "Item 3"].iter().map(|i| {
+///     Spans::from(vec![Span::styled("* ", Style::default().fg(Color::Blue)), Span::raw(*i)])
+/// });
 /// List::new(items)
 ///     .block(Block::default().title("List").borders(Borders::ALL))
 ///     .style(Style::default().fg(Color::White))
-///     .highlight_style(Style::default().modifier(Modifier::ITALIC))
+///     .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
 ///     .highlight_symbol(">>");
 /// ```
 #[derive(Debug, Clone)]
 pub struct List<'b, L>
 where
-    L: Iterator<Item = Text<'b>>,
+    L: Iterator,
+    L::Item: Into<text::Text<'b>>,
 {
     block: Option<Block<'b>>,
     items: L,
@@ -63,11 +174,14 @@ where
     highlight_style: Style,
     /// Symbol in front of the selected item (Shift all items to the right)
     highlight_symbol: Option<&'b str>,
+    /// Number of items, when the iterator does not know it
+    item_count: Option<usize>,
 }
 
 impl<'b, L> Default for List<'b, L>
 where
-    L: Iterator<Item = Text<'b>> + Default,
+    L: Iterator + Default,
+    L::Item: Into<text::Text<'b>>,
 {
     fn default() -> List<'b, L> {
         List {
@@ -77,13 +191,15 @@ where
             start_corner: Corner::TopLeft,
             highlight_style: Style::default(),
             highlight_symbol: None,
+            item_count: None,
         }
     }
 }
 
 impl<'b, L> List<'b, L>
 where
-    L: Iterator<Item = Text<'b>>,
+    L: Iterator,
+    L::Item: Into<text::Text<'b>>,
 {
     pub fn new(items: L) -> List<'b, L> {
         List {
@@ -93,6 +209,7 @@ where
             start_corner: Corner::TopLeft,
             highlight_style: Style::default(),
             highlight_symbol: None,
+            item_count: None,
         }
     }
 
@@ -103,7 +220,7 @@ where
 
     pub fn items<I>(mut self, items: I) -> List<'b, L>
     where
-        I: IntoIterator<Item = Text<'b>, IntoIter = L>,
+        I: IntoIterator<Item = L::Item, IntoIter = L>,
     {
         self.items = items.into_iter();
         self
@@ -128,30 +245,46 @@ where
         self.start_corner = corner;
         self
     }
+
+    /// Sets the number of items, for iterators which do not know it (such as filtered ones).
+    /// Otherwise the list can only be scrolled one item past the last visible one at a time.
+    pub fn item_count(mut self, count: usize) -> List<'b, L> {
+        self.item_count = Some(count);
+        self
+    }
 }
 
 impl<'b, L> StatefulWidget for List<'b, L>
 where
-    L: Iterator<Item = Text<'b>>,
+    L: Iterator,
+    L::Item: Into<text::Text<'b>>,
 {
     type State = ListState;
 
     fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let theme = *buf.theme();
+        self.style = theme.text.patch(self.style);
+        self.highlight_style = theme.selection.patch(self.highlight_style);
+
         let list_area = match self.block {
-            Some(ref mut b) => {
-                b.render(area, buf);
+            Some(ref b) => {
+                b.clone().render(area, buf);
                 b.inner(area)
             }
             None => area,
         };
 
+        state.area = list_area;
+        state.start_corner = self.start_corner;
+
         if list_area.width < 1 || list_area.height < 1 {
+            state.len = 0;
             return;
         }
 
         let list_height = list_area.height as usize;
 
-        buf.set_background(list_area, self.style.bg);
+        buf.set_style(list_area, self.style);
 
         // Use highlight_style only if something is selected
         let (selected, highlight_style) = match state.selected {
@@ -163,6 +296,8 @@ where
             .take(highlight_symbol.width())
             .collect::<String>();
 
+        let item_count = self.item_count.or_else(|| exact_len(&self.items));
+
         // Make sure the list show the selected item
         state.offset = if let Some(selected) = selected {
             if selected >= list_height + state.offset - 1 {
@@ -172,16 +307,22 @@ where
             } else {
                 state.offset
             }
+        } else if let Some(count) = item_count {
+            // The list may have shrunk since the last render
+            state.offset.min(count.saturating_sub(list_height))
         } else {
-            0
+            state.offset
         };
 
-        for (i, item) in self
-            .items
-            .skip(state.offset)
-            .enumerate()
-            .take(list_area.height as usize)
-        {
+        let mut items = self.items;
+        let skipped = items.by_ref().take(state.offset).count();
+        if skipped < state.offset {
+            // Too few items to draw any, the next render starts from the last page
+            state.offset = skipped.saturating_sub(list_height);
+        }
+        let mut visible = 0;
+        for (i, item) in items.by_ref().enumerate().take(list_area.height as usize) {
+            visible += 1;
             let (x, y) = match self.start_corner {
                 Corner::TopLeft => (list_area.left(), list_area.top() + i as u16),
                 Corner::BottomLeft => (list_area.left(), list_area.bottom() - (i + 1) as u16),
@@ -207,22 +348,30 @@ where
                 (x, None)
             };
 
-            let max_element_width = (list_area.width - (elem_x - x)) as usize;
-            match item {
-                Text::Raw(ref v) => {
-                    buf.set_stringn(elem_x, y, v, max_element_width, style.unwrap_or(self.style));
+            let max_element_width = list_area.width - (elem_x - x);
+            // Items are displayed on a single row
+            if let Some(line) = item.into().lines.first_mut() {
+                if let Some(style) = style {
+                    line.0
+                        .iter_mut()
+                        .for_each(|span| span.style = span.style.patch(style));
                 }
-                Text::Styled(ref v, s) => {
-                    buf.set_stringn(elem_x, y, v, max_element_width, style.unwrap_or(s));
-                }
-            };
+                buf.set_spans(elem_x, y, line, max_element_width, self.style);
+            }
+        }
+        // The remaining items are not counted, one more at most tells whether there are any
+        state.len = item_count.unwrap_or_else(|| skipped + visible + items.take(1).count());
+
+        if let Some(ref b) = self.block {
+            b.render_scrollbar(area, buf, &state.scrollbar_state());
         }
     }
 }
 
 impl<'b, L> Widget for List<'b, L>
 where
-    L: Iterator<Item = Text<'b>>,
+    L: Iterator,
+    L::Item: Into<text::Text<'b>>,
 {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let mut state = ListState::default();
@@ -14,6 +14,10 @@
 //! - [`Gauge`]
 //! - [`Sparkline`]
 //! - [`Clear`]
+//! - [`Input`]
+//! - [`TextArea`]
+//! - [`Tree`]
+//! - [`Scrollbar`]
 
 use bitflags::bitflags;
 use std::borrow::Cow;
@@ -24,23 +28,31 @@ pub mod canvas;
 mod chart;
 mod clear;
 mod gauge;
+mod input;
 mod list;
 mod paragraph;
 mod reflow;
+mod scrollbar;
 mod sparkline;
 mod table;
 mod tabs;
+mod textarea;
+mod tree;
 
 pub use self::barchart::BarChart;
 pub use self::block::{Block, BorderType};
 pub use self::chart::{Axis, Chart, Dataset, GraphType};
 pub use self::clear::Clear;
 pub use self::gauge::Gauge;
+pub use self::input::{Input, InputState};
 pub use self::list::{List, ListState};
 pub use self::paragraph::Paragraph;
+pub use self::scrollbar::{Scrollbar, ScrollbarOrientation, ScrollbarState};
 pub use self::sparkline::Sparkline;
-pub use self::table::{Row, Table, TableState};
+pub use self::table::{IntoCell, Row, Table, TableState};
 pub use self::tabs::Tabs;
+pub use self::textarea::{TextArea, TextAreaState};
+pub use self::tree::{Tree, TreeItem, TreeState};
 
 use crate::buffer::Buffer;
 use crate::layout::Rect;
@@ -203,3 +215,15 @@ pub trait StatefulWidget {
     type State;
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State);
 }
+
+/// Number of items left in `iter` when it reports it exactly, which is free to compute, unlike
+/// counting the items.
+fn exact_len<I>(iter: &I) -> Option<usize>
+where
+    I: Iterator,
+{
+    match iter.size_hint() {
+        (lower, Some(upper)) if lower == upper => Some(lower),
+        _ => None,
+    }
+}
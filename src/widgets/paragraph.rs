@@ -1,12 +1,12 @@
-use either::Either;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
 use crate::buffer::Buffer;
 use crate::layout::{Alignment, Rect};
 use crate::style::Style;
+use crate::text::{self, markup};
 use crate::widgets::reflow::{LineComposer, LineTruncator, Styled, WordWrapper};
-use crate::widgets::{Block, Text, Widget};
+use crate::widgets::{Block, ScrollbarState, StatefulWidget, Widget};
 
 fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment) -> u16 {
     match alignment {
@@ -18,27 +18,40 @@ fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment)
 
 /// A widget to display some text.
 ///
+/// The text is anything converting into a [`text::Text`](crate::text::Text): a string, some
+/// [`Spans`](crate::text::Spans) or an iterator over an array of [`Text`] fragments. The style
+/// of each part is applied on top of the style of the paragraph.
+///
+/// The text is drawn as is. [`Paragraph::try_markup`] builds a paragraph from a string styled
+/// with inline [markup](crate::text::markup) instead, such as `{fg=red;mod=bold some text}`.
+///
+/// [`Text`]: crate::widgets::Text
+///
+/// When rendered as a [`StatefulWidget`], the number of lines of the text and the scrolling
+/// position are written to a [`ScrollbarState`].
+///
 /// # Examples
 ///
 /// ```
-/// # use tui::widgets::{Block, Borders, Paragraph, Text};
+/// # use tui::widgets::{Block, Borders, Paragraph};
 /// # use tui::style::{Style, Color};
 /// # use tui::layout::{Alignment};
-/// let text = [
-///     Text::raw("First line\n"),
-///     Text::styled("Second line\n", Style::default().fg(Color::Red))
+/// # use tui::text::{Span, Spans};
+/// let text = vec![
+///     Spans::from("First line"),
+///     Spans::from(vec![
+///         Span::styled("Second", Style::default().fg(Color::Red)),
+///         Span::raw(" line"),
+///     ]),
 /// ];
-/// Paragraph::new(text.iter())
+/// Paragraph::new(text)
 ///     .block(Block::default().title("Paragraph").borders(Borders::ALL))
 ///     .style(Style::default().fg(Color::White).bg(Color::Black))
 ///     .alignment(Alignment::Center)
 ///     .wrap(true);
 /// ```
 #[derive(Debug, Clone)]
-pub struct Paragraph<'a, 't, T>
-where
-    T: Iterator<Item = &'t Text<'t>>,
-{
+pub struct Paragraph<'a> {
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
     /// Widget style
@@ -46,113 +59,173 @@ where
     /// Wrap the text or not
     wrapping: bool,
     /// The text to display
-    text: T,
-    /// Should we parse the text for embedded commands
-    raw: bool,
+    text: text::Text<'a>,
     /// Scroll
     scroll: u16,
     /// Aligenment of the text
     alignment: Alignment,
 }
 
-impl<'a, 't, T> Paragraph<'a, 't, T>
-where
-    T: Iterator<Item = &'t Text<'t>>,
-{
-    pub fn new(text: T) -> Paragraph<'a, 't, T> {
+impl<'a> Paragraph<'a> {
+    pub fn new<T>(text: T) -> Paragraph<'a>
+    where
+        T: Into<text::Text<'a>>,
+    {
         Paragraph {
             block: None,
             style: Default::default(),
             wrapping: false,
-            raw: false,
-            text,
+            text: text.into(),
             scroll: 0,
             alignment: Alignment::Left,
         }
     }
 
-    pub fn block(mut self, block: Block<'a>) -> Paragraph<'a, 't, T> {
+    /// Parses the [markup](crate::text::markup) of `text` and displays the result. Characters
+    /// escaped with a backslash, such as `\{` or `\\`, are displayed without it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the location of the malformed markup, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::Paragraph;
+    /// let paragraph = Paragraph::try_markup("{fg=red;mod=bold error}: file not found").unwrap();
+    /// assert!(Paragraph::try_markup("{fg=redd oops}").is_err());
+    /// ```
+    pub fn try_markup(text: &'a str) -> Result<Paragraph<'a>, markup::Error> {
+        markup::parse(text).map(Paragraph::new)
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Paragraph<'a> {
         self.block = Some(block);
         self
     }
 
-    pub fn style(mut self, style: Style) -> Paragraph<'a, 't, T> {
+    pub fn style(mut self, style: Style) -> Paragraph<'a> {
         self.style = style;
         self
     }
 
-    pub fn wrap(mut self, flag: bool) -> Paragraph<'a, 't, T> {
+    pub fn wrap(mut self, flag: bool) -> Paragraph<'a> {
         self.wrapping = flag;
         self
     }
 
-    pub fn raw(mut self, flag: bool) -> Paragraph<'a, 't, T> {
-        self.raw = flag;
+    /// Has no effect, the text given to [`Paragraph::new`] is always drawn as is.
+    #[deprecated(
+        since = "0.10.0",
+        note = "the text is always drawn as is, use `Paragraph::try_markup` to parse markup"
+    )]
+    pub fn raw(self, _flag: bool) -> Paragraph<'a> {
         self
     }
 
-    pub fn scroll(mut self, offset: u16) -> Paragraph<'a, 't, T> {
+    pub fn scroll(mut self, offset: u16) -> Paragraph<'a> {
         self.scroll = offset;
         self
     }
 
-    pub fn alignment(mut self, alignment: Alignment) -> Paragraph<'a, 't, T> {
+    pub fn alignment(mut self, alignment: Alignment) -> Paragraph<'a> {
         self.alignment = alignment;
         self
     }
 }
 
-impl<'a, 't, 'b, T> Widget for Paragraph<'a, 't, T>
-where
-    T: Iterator<Item = &'t Text<'t>>,
-{
-    fn render(mut self, area: Rect, buf: &mut Buffer) {
+impl<'a> Paragraph<'a> {
+    /// Draws the paragraph and updates its scrolling position. The lines below the area are only
+    /// composed, to know the length of the text, when `count_lines` is set.
+    fn render_text(
+        mut self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ScrollbarState,
+        count_lines: bool,
+    ) {
+        let theme = *buf.theme();
+        self.style = theme.text.patch(self.style);
+
         let text_area = match self.block {
-            Some(ref mut b) => {
-                b.render(area, buf);
+            Some(ref b) => {
+                b.clone().render(area, buf);
                 b.inner(area)
             }
             None => area,
         };
 
+        state.position = self.scroll as usize;
+        state.viewport_length = text_area.height as usize;
+        state.content_length = 0;
+
         if text_area.height < 1 {
             return;
         }
 
-        buf.set_background(text_area, self.style.bg);
+        buf.set_style(text_area, self.style);
 
         let style = self.style;
-        let mut styled = self.text.by_ref().flat_map(|t| match *t {
-            Text::Raw(ref d) => {
-                let data: &'t str = d; // coerce to &str
-                Either::Left(UnicodeSegmentation::graphemes(data, true).map(|g| Styled(g, style)))
-            }
-            Text::Styled(ref d, s) => {
-                let data: &'t str = d; // coerce to &str
-                Either::Right(UnicodeSegmentation::graphemes(data, true).map(move |g| Styled(g, s)))
-            }
+        let mut styled = self.text.lines.iter().enumerate().flat_map(|(i, line)| {
+            // Lines are separated the way the composers expect them
+            let newline = if i > 0 {
+                Some(Styled("\n", style))
+            } else {
+                None
+            };
+            newline.into_iter().chain(line.0.iter().flat_map(|span| {
+                let style = style.patch(span.style);
+                UnicodeSegmentation::graphemes(span.content.as_ref(), true)
+                    .map(move |g| Styled(g, style))
+            }))
         });
 
         let mut line_composer: Box<dyn LineComposer> = if self.wrapping {
-            Box::new(WordWrapper::new(&mut styled, text_area.width))
+            Box::new(WordWrapper::new(&mut styled, text_area.width, true))
         } else {
             Box::new(LineTruncator::new(&mut styled, text_area.width))
         };
-        let mut y = 0;
+        let scroll = self.scroll as usize;
+        let mut lines = 0;
         while let Some((current_line, current_line_width)) = line_composer.next_line() {
-            if y >= self.scroll {
+            if !count_lines && lines >= scroll + state.viewport_length {
+                break;
+            }
+            if lines >= scroll && lines < scroll + state.viewport_length {
+                let y = text_area.top() + (lines - scroll) as u16;
                 let mut x = get_line_offset(current_line_width, text_area.width, self.alignment);
                 for Styled(symbol, style) in current_line {
-                    buf.get_mut(text_area.left() + x, text_area.top() + y - self.scroll)
+                    buf.get_mut(text_area.left() + x, y)
                         .set_symbol(symbol)
                         .set_style(*style);
                     x += symbol.width() as u16;
                 }
             }
-            y += 1;
-            if y >= text_area.height + self.scroll {
-                break;
-            }
+            lines += 1;
+        }
+        state.content_length = lines;
+
+        if let Some(ref b) = self.block {
+            b.render_scrollbar(area, buf, state);
         }
     }
 }
+
+/// The state is the scrolling position of the paragraph, the whole text being composed to know
+/// its length.
+impl<'a> StatefulWidget for Paragraph<'a> {
+    type State = ScrollbarState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_text(area, buf, state, true);
+    }
+}
+
+impl<'a> Widget for Paragraph<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ScrollbarState::default();
+        // Only a scrollbar needs the length of the text
+        let count_lines = matches!(self.block, Some(ref b) if b.has_scrollbar());
+        self.render_text(area, buf, &mut state, count_lines);
+    }
+}
@@ -19,18 +19,22 @@ pub struct WordWrapper<'a, 'b> {
     max_line_width: u16,
     current_line: Vec<Styled<'a>>,
     next_line: Vec<Styled<'a>>,
+    /// Removes the leading whitespace of the lines
+    trim: bool,
 }
 
 impl<'a, 'b> WordWrapper<'a, 'b> {
     pub fn new(
         symbols: &'b mut dyn Iterator<Item = Styled<'a>>,
         max_line_width: u16,
+        trim: bool,
     ) -> WordWrapper<'a, 'b> {
         WordWrapper {
             symbols,
             max_line_width,
             current_line: vec![],
             next_line: vec![],
+            trim,
         }
     }
 }
@@ -60,7 +64,7 @@ impl<'a, 'b> LineComposer<'a> for WordWrapper<'a, 'b> {
             // Ignore characters wider that the total max width.
             if symbol.width() as u16 > self.max_line_width
                 // Skip leading whitespace.
-                || symbol_whitespace && symbol != "\n" && current_line_width == 0
+                || self.trim && symbol_whitespace && symbol != "\n" && current_line_width == 0
             {
                 continue;
             }
@@ -94,7 +98,9 @@ impl<'a, 'b> LineComposer<'a> for WordWrapper<'a, 'b> {
                 // Push the remainder to the next line but strip leading whitespace:
                 {
                     let remainder = &self.current_line[truncate_at..];
-                    if let Some(remainder_nonwhite) = remainder
+                    if !self.trim {
+                        self.next_line.extend_from_slice(remainder);
+                    } else if let Some(remainder_nonwhite) = remainder
                         .iter()
                         .position(|Styled(c, _)| !c.chars().all(&char::is_whitespace))
                     {
@@ -196,6 +202,7 @@ mod test {
 
     enum Composer {
         WordWrapper,
+        UntrimmedWordWrapper,
         LineTruncator,
     }
 
@@ -203,7 +210,10 @@ mod test {
         let style = Default::default();
         let mut styled = UnicodeSegmentation::graphemes(text, true).map(|g| Styled(g, style));
         let mut composer: Box<dyn LineComposer> = match which {
-            Composer::WordWrapper => Box::new(WordWrapper::new(&mut styled, text_area_width)),
+            Composer::WordWrapper => Box::new(WordWrapper::new(&mut styled, text_area_width, true)),
+            Composer::UntrimmedWordWrapper => {
+                Box::new(WordWrapper::new(&mut styled, text_area_width, false))
+            }
             Composer::LineTruncator => Box::new(LineTruncator::new(&mut styled, text_area_width)),
         };
         let mut lines = vec![];
@@ -443,4 +453,14 @@ mod test {
         let (word_wrapper_space, _) = run_composer(Composer::WordWrapper, &text_space, width);
         assert_eq!(word_wrapper_space, vec!["AAAAAAAAAAAAAAA AAAA", "AAA",]);
     }
+
+    #[test]
+    fn line_composer_word_wrapper_keeps_whitespace_without_trim() {
+        let width = 10;
+        let text = "  indented text\n    more";
+        let (word_wrapper, _) = run_composer(Composer::UntrimmedWordWrapper, text, width);
+        assert_eq!(word_wrapper, vec!["  indented", " text", "    more"]);
+        let (word_wrapper, _) = run_composer(Composer::WordWrapper, text, width);
+        assert_eq!(word_wrapper, vec!["indented", "text", "more"]);
+    }
 }
@@ -0,0 +1,175 @@
+use crate::buffer::Buffer;
+use crate::layout::Rect;
+use crate::style::Style;
+use crate::symbols::scrollbar;
+use crate::widgets::StatefulWidget;
+
+/// Position of a viewport in some scrollable content, as displayed by a [`Scrollbar`].
+///
+/// [`ListState`], [`TableState`] and [`Paragraph`] can fill it in for you.
+///
+/// [`ListState`]: crate::widgets::ListState
+/// [`TableState`]: crate::widgets::TableState
+/// [`Paragraph`]: crate::widgets::Paragraph
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScrollbarState {
+    /// Total number of lines (or columns) of the content
+    pub content_length: usize,
+    /// Index of the first visible line (or column)
+    pub position: usize,
+    /// Number of lines (or columns) visible at once
+    pub viewport_length: usize,
+}
+
+impl ScrollbarState {
+    /// Returns the offset and the length of the thumb along a track of the given length, or `None`
+    /// if the whole content is visible.
+    fn thumb(&self, track_length: u16) -> Option<(u16, u16)> {
+        if track_length == 0 || self.content_length <= self.viewport_length {
+            return None;
+        }
+        let track_length = track_length as usize;
+        let thumb_length = (track_length * self.viewport_length / self.content_length)
+            .max(1)
+            .min(track_length);
+        let max_position = self.content_length - self.viewport_length;
+        let position = self.position.min(max_position);
+        let offset = (track_length - thumb_length) * position / max_position;
+        Some((offset as u16, thumb_length as u16))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScrollbarOrientation {
+    /// Drawn along the right column of its area
+    Vertical,
+    /// Drawn along the bottom row of its area
+    Horizontal,
+}
+
+/// A widget showing how far through some content a viewport is. Nothing is drawn when the whole
+/// content is visible.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Scrollbar, ScrollbarOrientation};
+/// # use tui::style::{Style, Color};
+/// # use tui::symbols;
+/// Scrollbar::new(ScrollbarOrientation::Vertical)
+///     .symbols(symbols::scrollbar::SHADED)
+///     .style(Style::default().fg(Color::Gray))
+///     .thumb_style(Style::default().fg(Color::White));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Scrollbar {
+    orientation: ScrollbarOrientation,
+    symbols: scrollbar::Set,
+    /// Style of the track
+    style: Style,
+    /// Style of the thumb
+    thumb_style: Style,
+}
+
+impl Default for Scrollbar {
+    fn default() -> Scrollbar {
+        Scrollbar::new(ScrollbarOrientation::Vertical)
+    }
+}
+
+impl Scrollbar {
+    pub fn new(orientation: ScrollbarOrientation) -> Scrollbar {
+        Scrollbar {
+            orientation,
+            symbols: match orientation {
+                ScrollbarOrientation::Vertical => scrollbar::VERTICAL,
+                ScrollbarOrientation::Horizontal => scrollbar::HORIZONTAL,
+            },
+            style: Default::default(),
+            thumb_style: Default::default(),
+        }
+    }
+
+    pub fn symbols(mut self, symbols: scrollbar::Set) -> Scrollbar {
+        self.symbols = symbols;
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Scrollbar {
+        self.style = style;
+        self
+    }
+
+    pub fn thumb_style(mut self, style: Style) -> Scrollbar {
+        self.thumb_style = style;
+        self
+    }
+
+    pub fn orientation(&self) -> ScrollbarOrientation {
+        self.orientation
+    }
+}
+
+impl StatefulWidget for Scrollbar {
+    type State = ScrollbarState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let theme = *buf.theme();
+        self.style = theme.scrollbar.patch(self.style);
+        self.thumb_style = theme.scrollbar_thumb.patch(self.thumb_style);
+
+        if area.width < 1 || area.height < 1 {
+            return;
+        }
+        let track_length = match self.orientation {
+            ScrollbarOrientation::Vertical => area.height,
+            ScrollbarOrientation::Horizontal => area.width,
+        };
+        let (thumb_offset, thumb_length) = match state.thumb(track_length) {
+            Some(thumb) => thumb,
+            None => return,
+        };
+        for i in 0..track_length {
+            let (x, y) = match self.orientation {
+                ScrollbarOrientation::Vertical => (area.right() - 1, area.top() + i),
+                ScrollbarOrientation::Horizontal => (area.left() + i, area.bottom() - 1),
+            };
+            let (symbol, style) = if i >= thumb_offset && i < thumb_offset + thumb_length {
+                (self.symbols.thumb, self.thumb_style)
+            } else {
+                (self.symbols.track, self.style)
+            };
+            buf.get_mut(x, y).set_symbol(symbol).set_style(style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrollbar_thumb_covers_the_visible_part_of_the_content() {
+        let state = ScrollbarState {
+            content_length: 100,
+            position: 0,
+            viewport_length: 10,
+        };
+        assert_eq!(state.thumb(20), Some((0, 2)));
+        let state = ScrollbarState {
+            position: 90,
+            ..state
+        };
+        assert_eq!(state.thumb(20), Some((18, 2)));
+        let state = ScrollbarState {
+            position: 1000,
+            ..state
+        };
+        assert_eq!(state.thumb(20), Some((18, 2)));
+        let state = ScrollbarState {
+            content_length: 10,
+            ..state
+        };
+        assert_eq!(state.thumb(20), None);
+    }
+}
@@ -76,9 +76,12 @@ impl<'a> Sparkline<'a> {
 
 impl<'a> Widget for Sparkline<'a> {
     fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let theme = *buf.theme();
+        self.style = theme.bar.patch(self.style);
+
         let spark_area = match self.block {
-            Some(ref mut b) => {
-                b.render(area, buf);
+            Some(ref b) => {
+                b.clone().render(area, buf);
                 b.inner(area)
             }
             None => area,
@@ -120,8 +123,7 @@ impl<'a> Widget for Sparkline<'a> {
                 };
                 buf.get_mut(spark_area.left() + i as u16, spark_area.top() + j)
                     .set_symbol(symbol)
-                    .set_fg(self.style.fg)
-                    .set_bg(self.style.bg);
+                    .set_style(self.style);
 
                 if *d > 8 {
                     *d -= 8;
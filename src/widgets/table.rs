@@ -1,8 +1,10 @@
 use crate::{
     buffer::Buffer,
+    event::{MouseButton, MouseEvent, MouseEventKind},
     layout::{Constraint, Rect},
     style::Style,
-    widgets::{Block, StatefulWidget, Widget},
+    text::{Span, Spans},
+    widgets::{exact_len, Block, ScrollbarState, StatefulWidget, Widget},
 };
 use cassowary::{
     strength::{MEDIUM, REQUIRED, WEAK},
@@ -20,6 +22,10 @@ use unicode_width::UnicodeWidthStr;
 pub struct TableState {
     offset: usize,
     selected: Option<usize>,
+    /// Area the rows (without the header) were drawn to during the last render
+    area: Rect,
+    /// Number of rows during the last render
+    len: usize,
 }
 
 impl Default for TableState {
@@ -27,6 +33,8 @@ impl Default for TableState {
         TableState {
             offset: 0,
             selected: None,
+            area: Rect::default(),
+            len: 0,
         }
     }
 }
@@ -42,14 +50,122 @@ impl TableState {
             self.offset = 0;
         }
     }
+
+    /// Index of the first visible row.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the index of the row displayed at the given position of the screen during the
+    /// last render, if any. The header and the gap below it do not belong to any row.
+    pub fn row_at(&self, x: u16, y: u16) -> Option<usize> {
+        if !self.area.contains(x, y) {
+            return None;
+        }
+        let index = self.offset + (y - self.area.top()) as usize;
+        if index < self.len {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// Scrolls the table by `n` rows towards its first row. The selected row, if any, is moved
+    /// so that it stays visible.
+    pub fn scroll_up(&mut self, n: usize) {
+        self.offset = self.offset.saturating_sub(n);
+        self.keep_selection_visible();
+    }
+
+    /// Scrolls the table by `n` rows towards its last row. The selected row, if any, is moved so
+    /// that it stays visible.
+    pub fn scroll_down(&mut self, n: usize) {
+        let max_offset = self.len.saturating_sub(self.area.height as usize);
+        self.offset = max_offset.min(self.offset + n);
+        self.keep_selection_visible();
+    }
+
+    /// Selects the row under a left click and scrolls the table with the mouse wheel, based on
+    /// the geometry of the last render. Returns whether the state changed.
+    pub fn handle_mouse_event(&mut self, event: &MouseEvent) -> bool {
+        if !self.area.contains(event.column, event.row) {
+            return false;
+        }
+        let (offset, selected) = (self.offset, self.selected);
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(index) = self.row_at(event.column, event.row) {
+                    self.selected = Some(index);
+                }
+            }
+            MouseEventKind::ScrollUp => self.scroll_up(1),
+            MouseEventKind::ScrollDown => self.scroll_down(1),
+            _ => {}
+        }
+        offset != self.offset || selected != self.selected
+    }
+
+    /// Scrolling position of the last render, to be displayed by a
+    /// [`Scrollbar`](crate::widgets::Scrollbar).
+    pub fn scrollbar_state(&self) -> ScrollbarState {
+        ScrollbarState {
+            content_length: self.len,
+            position: self.offset,
+            viewport_length: self.area.height as usize,
+        }
+    }
+
+    fn keep_selection_visible(&mut self) {
+        let height = self.area.height as usize;
+        if height == 0 {
+            return;
+        }
+        if let Some(selected) = self.selected {
+            if selected < self.offset {
+                self.selected = Some(self.offset);
+            } else if selected >= self.offset + height {
+                self.selected = Some(self.offset + height - 1);
+            }
+        }
+    }
+}
+
+/// Content of a [`Table`] cell.
+///
+/// It is implemented for [`Span`] and [`Spans`] to mix several styles in a cell, and for any
+/// type implementing [`Display`], drawn with the style of its row.
+pub trait IntoCell<'a> {
+    fn into_cell(self) -> Spans<'a>;
+}
+
+impl<'a, T> IntoCell<'a> for T
+where
+    T: Display,
+{
+    fn into_cell(self) -> Spans<'a> {
+        Spans::from(self.to_string())
+    }
+}
+
+impl<'a, 's: 'a> IntoCell<'a> for Span<'s> {
+    fn into_cell(self) -> Spans<'a> {
+        Spans::from(self)
+    }
+}
+
+impl<'a, 's: 'a> IntoCell<'a> for Spans<'s> {
+    fn into_cell(self) -> Spans<'a> {
+        self
+    }
 }
 
 /// Holds data to be displayed in a Table widget
+///
+/// Cells are anything implementing [`IntoCell`].
 #[derive(Debug, Clone)]
 pub enum Row<D>
 where
     D: Iterator,
-    D::Item: Display,
 {
     Data(D),
     StyledData(D, Style),
@@ -57,6 +173,10 @@ where
 
 /// A widget to display data in formatted columns
 ///
+/// The rows are only iterated up to the last visible one. The number of rows, used to scroll the
+/// table with the mouse and to draw a scrollbar, is known from iterators of known size, and should
+/// be given with [`Table::row_count`] for the other ones.
+///
 /// # Examples
 ///
 /// ```
@@ -101,6 +221,8 @@ pub struct Table<'a, H, R> {
     highlight_symbol: Option<&'a str>,
     /// Data to display in each row
     rows: R,
+    /// Number of rows, when the iterator does not know it
+    row_count: Option<usize>,
 }
 
 impl<'a, H, R> Default for Table<'a, H, R>
@@ -120,6 +242,7 @@ where
             highlight_style: Style::default(),
             highlight_symbol: None,
             rows: R::default(),
+            row_count: None,
         }
     }
 }
@@ -127,7 +250,7 @@ impl<'a, H, D, R> Table<'a, H, R>
 where
     H: Iterator,
     D: Iterator,
-    D::Item: Display,
+    D::Item: IntoCell<'a>,
     R: Iterator<Item = Row<D>>,
 {
     pub fn new(header: H, rows: R) -> Table<'a, H, R> {
@@ -142,6 +265,7 @@ where
             highlight_style: Style::default(),
             highlight_symbol: None,
             rows,
+            row_count: None,
         }
     }
     pub fn block(mut self, block: Block<'a>) -> Table<'a, H, R> {
@@ -207,29 +331,41 @@ where
         self.header_gap = gap;
         self
     }
+
+    /// Sets the number of rows, for iterators which do not know it (such as filtered ones).
+    /// Otherwise the table can only be scrolled one row past the last visible one at a time.
+    pub fn row_count(mut self, count: usize) -> Table<'a, H, R> {
+        self.row_count = Some(count);
+        self
+    }
 }
 
 impl<'a, H, D, R> StatefulWidget for Table<'a, H, R>
 where
     H: Iterator,
-    H::Item: Display,
+    H::Item: IntoCell<'a>,
     D: Iterator,
-    D::Item: Display,
+    D::Item: IntoCell<'a>,
     R: Iterator<Item = Row<D>>,
 {
     type State = TableState;
 
     fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let theme = *buf.theme();
+        self.style = theme.text.patch(self.style);
+        self.header_style = theme.header.patch(self.header_style);
+        self.highlight_style = theme.selection.patch(self.highlight_style);
+
         // Render block if necessary and get the drawing area
         let table_area = match self.block {
-            Some(ref mut b) => {
-                b.render(area, buf);
+            Some(ref b) => {
+                b.clone().render(area, buf);
                 b.inner(area)
             }
             None => area,
         };
 
-        buf.set_background(table_area, self.style.bg);
+        buf.set_style(table_area, self.style);
 
         let mut solver = Solver::new();
         let mut var_indices = HashMap::new();
@@ -283,7 +419,7 @@ where
         // Draw header
         if y < table_area.bottom() {
             for (w, t) in solved_widths.iter().zip(self.header.by_ref()) {
-                buf.set_stringn(x, y, format!("{}", t), *w as usize, self.header_style);
+                buf.set_spans(x, y, &t.into_cell(), *w, self.header_style);
                 x += *w + self.column_spacing;
             }
         }
@@ -301,8 +437,13 @@ where
 
         // Draw rows
         let default_style = Style::default();
+        state.area = Rect::default();
+        state.len = 0;
         if y < table_area.bottom() {
             let remaining = (table_area.bottom() - y) as usize;
+            state.area = Rect::new(table_area.x, y, table_area.width, remaining as u16);
+
+            let row_count = self.row_count.or_else(|| exact_len(&self.rows));
 
             // Make sure the table shows the selected item
             state.offset = if let Some(selected) = selected {
@@ -313,30 +454,55 @@ where
                 } else {
                     state.offset
                 }
+            } else if let Some(count) = row_count {
+                // The table may have shrunk since the last render
+                state.offset.min(count.saturating_sub(remaining))
             } else {
-                0
+                state.offset
             };
-            for (i, row) in self.rows.skip(state.offset).take(remaining).enumerate() {
-                let (data, style, symbol) = match row {
-                    Row::Data(d) | Row::StyledData(d, _)
-                        if Some(i) == state.selected.map(|s| s - state.offset) =>
-                    {
-                        (d, highlight_style, highlight_symbol)
-                    }
-                    Row::Data(d) => (d, default_style, blank_symbol.as_ref()),
-                    Row::StyledData(d, s) => (d, s, blank_symbol.as_ref()),
+            let mut rows = self.rows;
+            let skipped = rows.by_ref().take(state.offset).count();
+            if skipped < state.offset {
+                // Too few rows to draw any, the next render starts from the last page
+                state.offset = skipped.saturating_sub(remaining);
+            }
+            let mut visible = 0;
+            for (i, row) in rows.by_ref().take(remaining).enumerate() {
+                visible += 1;
+                let (data, style) = match row {
+                    Row::Data(d) => (d, default_style),
+                    Row::StyledData(d, s) => (d, s),
+                };
+                // The highlight is applied on top of the style of the row and of its cells
+                let highlighted = Some(i) == state.selected.map(|s| s - state.offset);
+                let (style, symbol) = if highlighted {
+                    (style.patch(highlight_style), highlight_symbol)
+                } else {
+                    (style, blank_symbol.as_ref())
                 };
                 x = table_area.left();
                 for (c, (w, elt)) in solved_widths.iter().zip(data).enumerate() {
-                    let s = if c == 0 {
-                        format!("{}{}", symbol, elt)
+                    let (cell_x, _) = if c == 0 {
+                        buf.set_stringn(x, y + i as u16, symbol, *w as usize, style)
                     } else {
-                        format!("{}", elt)
+                        (x, y)
                     };
-                    buf.set_stringn(x, y + i as u16, s, *w as usize, style);
+                    let mut cell = elt.into_cell();
+                    if highlighted {
+                        cell.0
+                            .iter_mut()
+                            .for_each(|span| span.style = span.style.patch(highlight_style));
+                    }
+                    buf.set_spans(cell_x, y + i as u16, &cell, *w - (cell_x - x), style);
                     x += *w + self.column_spacing;
                 }
             }
+            // The remaining rows are not counted, one more at most tells whether there are any
+            state.len = row_count.unwrap_or_else(|| skipped + visible + rows.take(1).count());
+        }
+
+        if let Some(ref b) = self.block {
+            b.render_scrollbar(area, buf, &state.scrollbar_state());
         }
     }
 }
@@ -344,9 +510,9 @@ where
 impl<'a, H, D, R> Widget for Table<'a, H, R>
 where
     H: Iterator,
-    H::Item: Display,
+    H::Item: IntoCell<'a>,
     D: Iterator,
-    D::Item: Display,
+    D::Item: IntoCell<'a>,
     R: Iterator<Item = Row<D>>,
 {
     fn render(self, area: Rect, buf: &mut Buffer) {
@@ -4,10 +4,14 @@ use crate::buffer::Buffer;
 use crate::layout::{Margin, Rect};
 use crate::style::Style;
 use crate::symbols::line;
+use crate::text::Spans;
 use crate::widgets::{Block, Widget};
 
 /// A widget to display available tabs in a multiple panels context.
 ///
+/// Titles are anything a reference converts into [`Spans`], such as strings or some [`Spans`]
+/// mixing several styles.
+///
 /// # Examples
 ///
 /// ```
@@ -24,7 +28,7 @@ use crate::widgets::{Block, Widget};
 #[derive(Debug, Clone)]
 pub struct Tabs<'a, T>
 where
-    T: AsRef<str> + 'a,
+    T: 'a,
 {
     /// A block to wrap this widget in if necessary
     block: Option<Block<'a>>,
@@ -44,7 +48,7 @@ where
 
 impl<'a, T> Default for Tabs<'a, T>
 where
-    T: AsRef<str>,
+    T: 'a,
 {
     fn default() -> Tabs<'a, T> {
         Tabs {
@@ -64,7 +68,8 @@ where
 
 impl<'a, T> Tabs<'a, T>
 where
-    T: AsRef<str>,
+    T: 'a,
+    Spans<'a>: From<&'a T>,
 {
     pub fn block(mut self, block: Block<'a>) -> Tabs<'a, T> {
         self.block = Some(block);
@@ -100,16 +105,56 @@ where
         self.margin = margin;
         self
     }
+
+    /// Returns the index of the title displayed at the given position of the screen when the
+    /// tabs are rendered to `area`, if any. Dividers and the spaces around them do not belong to
+    /// any title.
+    pub fn tab_at(&self, area: Rect, x: u16, y: u16) -> Option<usize> {
+        let tabs_area = match self.block {
+            Some(ref b) => b.inner(area),
+            None => area,
+        }
+        .inner(&self.margin);
+
+        if tabs_area.height < 1 || y != tabs_area.top() {
+            return None;
+        }
+
+        // Same layout as the one used in render
+        let divider_width = self.divider.width() as u16 + 1;
+        let mut left = tabs_area.left();
+        for (i, title) in self.titles.iter().enumerate() {
+            if left >= tabs_area.right() {
+                break;
+            }
+            let right = tabs_area
+                .right()
+                .min(left + Spans::from(title).width() as u16);
+            if x >= left && x < right {
+                return Some(i);
+            }
+            if tabs_area.right() - right < divider_width {
+                break;
+            }
+            left = right + divider_width + 1;
+        }
+        None
+    }
 }
 
 impl<'a, T> Widget for Tabs<'a, T>
 where
-    T: AsRef<str>,
+    T: 'a,
+    Spans<'a>: From<&'a T>,
 {
     fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let theme = *buf.theme();
+        self.style = theme.text.patch(self.style);
+        self.highlight_style = theme.selection.patch(self.highlight_style);
+
         let tabs_area = match self.block {
-            Some(ref mut b) => {
-                b.render(area, buf);
+            Some(ref b) => {
+                b.clone().render(area, buf);
                 b.inner(area)
             }
             None => area,
@@ -120,7 +165,7 @@ where
             return;
         }
 
-        buf.set_background(tabs_area, self.style.bg);
+        buf.set_style(tabs_area, self.style);
 
         let mut x = tabs_area.left();
         let titles_length = self.titles.len();
@@ -128,31 +173,34 @@ where
         // divider actually requires a space before it, so we add one
         let divider_width = self.divider.width() as u16 + 1;
 
-        for (title, style, last_title) in self.titles.iter().enumerate().map(|(i, t)| {
-            let lt = i + 1 == titles_length;
-            if i == self.selected {
-                (t, self.highlight_style, lt)
-            } else {
-                (t, self.style, lt)
-            }
-        }) {
+        for (title, style, last_title, is_selected) in
+            self.titles.iter().enumerate().map(|(i, t)| {
+                let lt = i + 1 == titles_length;
+                if i == self.selected {
+                    (t, self.highlight_style, lt, true)
+                } else {
+                    (t, self.style, lt, false)
+                }
+            })
+        {
             if x >= tabs_area.right() {
                 break;
             }
 
             let mut space_remaining: isize = (tabs_area.right() as isize) - (x as isize);
-            let title_width = title.as_ref().width() as u16;
+            let mut title = Spans::from(title);
+            if is_selected {
+                title
+                    .0
+                    .iter_mut()
+                    .for_each(|span| span.style = span.style.patch(style));
+            }
+            let title_width = title.width() as u16;
             if title_width > space_remaining as u16 {
-                buf.set_stringn(
-                    x,
-                    tabs_area.top(),
-                    title.as_ref(),
-                    space_remaining as usize,
-                    style,
-                );
+                buf.set_spans(x, tabs_area.top(), &title, space_remaining as u16, style);
                 break;
             } else {
-                buf.set_string(x, tabs_area.top(), title.as_ref(), style);
+                buf.set_spans(x, tabs_area.top(), &title, title_width, style);
                 x += title_width;
                 space_remaining -= title_width as isize;
 
@@ -0,0 +1,665 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::buffer::Buffer;
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::layout::Rect;
+use crate::style::Style;
+use crate::widgets::reflow::{LineComposer, Styled, WordWrapper};
+use crate::widgets::{Block, StatefulWidget, Widget};
+
+/// Maximum number of snapshots kept for undo
+const MAX_HISTORY: usize = 100;
+
+/// A position in the text, as a line index and a byte offset in that line.
+type TextPosition = (usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Edit {
+    Insert,
+    Other,
+}
+
+#[derive(Debug, Clone)]
+struct Snapshot {
+    lines: Vec<String>,
+    cursor: TextPosition,
+}
+
+/// Content and editing state of a [`TextArea`].
+///
+/// The text is stored as a list of lines without their line breaks. The cursor always sits on a
+/// grapheme boundary.
+#[derive(Debug, Clone)]
+pub struct TextAreaState {
+    lines: Vec<String>,
+    cursor: TextPosition,
+    /// Other end of the selection, if any
+    anchor: Option<TextPosition>,
+    /// Number of rows scrolled out at the top during the last render
+    offset: usize,
+    /// Wrapping width of the last render, used to move the cursor by rows
+    width: Option<u16>,
+    /// Position of the cursor on the screen during the last render
+    cursor_position: Option<(u16, u16)>,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    /// Kind of the last edit, consecutive insertions are undone together
+    last_edit: Option<Edit>,
+}
+
+impl Default for TextAreaState {
+    fn default() -> TextAreaState {
+        TextAreaState {
+            lines: vec![String::new()],
+            cursor: (0, 0),
+            anchor: None,
+            offset: 0,
+            width: None,
+            cursor_position: None,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            last_edit: None,
+        }
+    }
+}
+
+impl TextAreaState {
+    /// Creates a state holding the given text, with the cursor at its start.
+    pub fn new(text: &str) -> TextAreaState {
+        TextAreaState {
+            lines: text.split('\n').map(String::from).collect(),
+            ..TextAreaState::default()
+        }
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Returns the whole text, lines being separated by `\n`.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Position of the cursor.
+    pub fn cursor(&self) -> TextPosition {
+        self.cursor
+    }
+
+    /// Where the terminal cursor should be placed according to the last render. `None` if the
+    /// cursor was not visible.
+    pub fn cursor_position(&self) -> Option<(u16, u16)> {
+        self.cursor_position
+    }
+
+    /// Start (inclusive) and end (exclusive) of the selected text, if any.
+    pub fn selection(&self) -> Option<(TextPosition, TextPosition)> {
+        match self.anchor {
+            Some(anchor) if anchor < self.cursor => Some((anchor, self.cursor)),
+            Some(anchor) if anchor > self.cursor => Some((self.cursor, anchor)),
+            _ => None,
+        }
+    }
+
+    pub fn selected_text(&self) -> Option<String> {
+        let ((start_row, start_col), (end_row, end_col)) = self.selection()?;
+        if start_row == end_row {
+            return Some(self.lines[start_row][start_col..end_col].to_string());
+        }
+        let mut text = self.lines[start_row][start_col..].to_string();
+        for line in &self.lines[start_row + 1..end_row] {
+            text.push('\n');
+            text.push_str(line);
+        }
+        text.push('\n');
+        text.push_str(&self.lines[end_row][..end_col]);
+        Some(text)
+    }
+
+    pub fn select_all(&mut self) {
+        self.anchor = Some((0, 0));
+        self.cursor = self.end();
+        self.last_edit = None;
+    }
+
+    /// Inserts a character at the cursor, replacing the selection if any. `\n` breaks the line.
+    pub fn insert_char(&mut self, c: char) {
+        if c == '\n' {
+            self.insert_newline();
+            return;
+        }
+        self.record(Edit::Insert);
+        self.delete_selection();
+        let (row, col) = self.cursor;
+        self.lines[row].insert(col, c);
+        self.cursor.1 += c.len_utf8();
+    }
+
+    /// Inserts some text at the cursor, replacing the selection if any.
+    pub fn insert_str(&mut self, s: &str) {
+        self.record(Edit::Other);
+        self.delete_selection();
+        let (row, col) = self.cursor;
+        let tail = self.lines[row].split_off(col);
+        let mut inserted = s.split('\n');
+        if let Some(first) = inserted.next() {
+            self.lines[row].push_str(first);
+        }
+        let mut new_row = row;
+        for line in inserted {
+            new_row += 1;
+            self.lines.insert(new_row, line.to_string());
+        }
+        self.cursor = (new_row, self.lines[new_row].len());
+        self.lines[new_row].push_str(&tail);
+    }
+
+    /// Breaks the line at the cursor, replacing the selection if any.
+    pub fn insert_newline(&mut self) {
+        self.record(Edit::Other);
+        self.delete_selection();
+        let (row, col) = self.cursor;
+        let tail = self.lines[row].split_off(col);
+        self.lines.insert(row + 1, tail);
+        self.cursor = (row + 1, 0);
+    }
+
+    /// Deletes the selection, or the grapheme before the cursor, joining the line with the
+    /// previous one at its start.
+    pub fn delete_backward(&mut self) {
+        let (row, col) = self.cursor;
+        // Nothing to delete at the start of the text
+        if self.selection().is_none() && row == 0 && col == 0 {
+            self.anchor = None;
+            return;
+        }
+        self.record(Edit::Other);
+        if self.delete_selection() {
+            return;
+        }
+        if col > 0 {
+            let start = prev_boundary(&self.lines[row], col);
+            self.lines[row].replace_range(start..col, "");
+            self.cursor.1 = start;
+        } else if row > 0 {
+            let line = self.lines.remove(row);
+            self.cursor = (row - 1, self.lines[row - 1].len());
+            self.lines[row - 1].push_str(&line);
+        }
+    }
+
+    /// Deletes the selection, or the grapheme after the cursor, joining the line with the next
+    /// one at its end.
+    pub fn delete_forward(&mut self) {
+        let (row, col) = self.cursor;
+        // Nothing to delete at the end of the text
+        if self.selection().is_none() && row + 1 == self.lines.len() && col == self.lines[row].len()
+        {
+            self.anchor = None;
+            return;
+        }
+        self.record(Edit::Other);
+        if self.delete_selection() {
+            return;
+        }
+        if col < self.lines[row].len() {
+            let end = next_boundary(&self.lines[row], col);
+            self.lines[row].replace_range(col..end, "");
+        } else if row + 1 < self.lines.len() {
+            let line = self.lines.remove(row + 1);
+            self.lines[row].push_str(&line);
+        }
+    }
+
+    /// Restores the text as it was before the last edit. Returns false if there is nothing to
+    /// undo.
+    pub fn undo(&mut self) -> bool {
+        match self.undo.pop() {
+            Some(snapshot) => {
+                let current = self.restore(snapshot);
+                self.redo.push(current);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Applies again the last undone edit. Returns false if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some(snapshot) => {
+                let current = self.restore(snapshot);
+                self.undo.push(current);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the cursor one grapheme to the left, to the end of the previous line when at the
+    /// start of a line. The selection is extended if `select` is true, otherwise the cursor
+    /// moves to its start.
+    pub fn move_left(&mut self, select: bool) {
+        let (row, col) = self.cursor;
+        let position = match self.selection() {
+            Some((start, _)) if !select => start,
+            _ if col > 0 => (row, prev_boundary(&self.lines[row], col)),
+            _ if row > 0 => (row - 1, self.lines[row - 1].len()),
+            _ => self.cursor,
+        };
+        self.move_to(position, select);
+    }
+
+    /// Moves the cursor one grapheme to the right, to the start of the next line when at the
+    /// end of a line. The selection is extended if `select` is true, otherwise the cursor moves
+    /// to its end.
+    pub fn move_right(&mut self, select: bool) {
+        let (row, col) = self.cursor;
+        let position = match self.selection() {
+            Some((_, end)) if !select => end,
+            _ if col < self.lines[row].len() => (row, next_boundary(&self.lines[row], col)),
+            _ if row + 1 < self.lines.len() => (row + 1, 0),
+            _ => self.cursor,
+        };
+        self.move_to(position, select);
+    }
+
+    /// Moves the cursor to the start of the current or previous word.
+    pub fn move_word_left(&mut self, select: bool) {
+        let (mut row, mut col) = self.cursor;
+        if col == 0 && row > 0 {
+            row -= 1;
+            col = self.lines[row].len();
+        }
+        let mut in_word = false;
+        let line = &self.lines[row];
+        for (i, g) in line[..col].grapheme_indices(true).rev() {
+            let is_space = g.chars().all(char::is_whitespace);
+            if in_word && is_space {
+                break;
+            }
+            in_word |= !is_space;
+            col = i;
+        }
+        self.move_to((row, col), select);
+    }
+
+    /// Moves the cursor to the end of the current or next word.
+    pub fn move_word_right(&mut self, select: bool) {
+        let (mut row, mut col) = self.cursor;
+        if col == self.lines[row].len() && row + 1 < self.lines.len() {
+            row += 1;
+            col = 0;
+        }
+        let mut in_word = false;
+        let line = &self.lines[row];
+        let start = col;
+        for (i, g) in line[start..].grapheme_indices(true) {
+            let is_space = g.chars().all(char::is_whitespace);
+            if in_word && is_space {
+                break;
+            }
+            in_word |= !is_space;
+            col = start + i + g.len();
+        }
+        self.move_to((row, col), select);
+    }
+
+    /// Moves the cursor one row up, keeping its column when possible. Rows are the wrapped
+    /// lines of the last render.
+    pub fn move_up(&mut self, select: bool) {
+        let rows = self.rows();
+        let current = visual_row(&rows, self.cursor);
+        let position = if current == 0 {
+            (0, 0)
+        } else {
+            let column = self.column_in_row(&rows[current]);
+            position_in_row(&self.lines, &rows, current - 1, column)
+        };
+        self.move_to(position, select);
+    }
+
+    /// Moves the cursor one row down, keeping its column when possible. Rows are the wrapped
+    /// lines of the last render.
+    pub fn move_down(&mut self, select: bool) {
+        let rows = self.rows();
+        let current = visual_row(&rows, self.cursor);
+        let position = if current + 1 == rows.len() {
+            self.end()
+        } else {
+            let column = self.column_in_row(&rows[current]);
+            position_in_row(&self.lines, &rows, current + 1, column)
+        };
+        self.move_to(position, select);
+    }
+
+    /// Moves the cursor to the start of its line.
+    pub fn move_home(&mut self, select: bool) {
+        self.move_to((self.cursor.0, 0), select);
+    }
+
+    /// Moves the cursor to the end of its line.
+    pub fn move_end(&mut self, select: bool) {
+        let row = self.cursor.0;
+        self.move_to((row, self.lines[row].len()), select);
+    }
+
+    /// Applies the usual editing bindings: characters are inserted, `Enter` breaks the line,
+    /// `Backspace` and `Delete` remove text, arrows, `Home` and `End` move the cursor (by words
+    /// with `Control`, extending the selection with `Shift`), `Control-z` and `Control-y` undo
+    /// and redo. Returns whether the key was handled.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        let select = key.modifiers.contains(KeyModifiers::SHIFT);
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        match key.code {
+            KeyCode::Char('z') if control => {
+                self.undo();
+            }
+            KeyCode::Char('y') if control => {
+                self.redo();
+            }
+            KeyCode::Char(c) if !control && !alt => self.insert_char(c),
+            KeyCode::Enter => self.insert_newline(),
+            KeyCode::Backspace => self.delete_backward(),
+            KeyCode::Delete => self.delete_forward(),
+            KeyCode::Left if control => self.move_word_left(select),
+            KeyCode::Left => self.move_left(select),
+            KeyCode::Right if control => self.move_word_right(select),
+            KeyCode::Right => self.move_right(select),
+            KeyCode::Up => self.move_up(select),
+            KeyCode::Down => self.move_down(select),
+            KeyCode::Home => self.move_home(select),
+            KeyCode::End => self.move_end(select),
+            _ => return false,
+        }
+        true
+    }
+
+    fn end(&self) -> TextPosition {
+        let row = self.lines.len() - 1;
+        (row, self.lines[row].len())
+    }
+
+    fn move_to(&mut self, position: TextPosition, select: bool) {
+        if select {
+            if self.anchor.is_none() {
+                self.anchor = Some(self.cursor);
+            }
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = position;
+        self.last_edit = None;
+    }
+
+    /// Saves the text before an edit so that it can be undone.
+    fn record(&mut self, edit: Edit) {
+        if edit == Edit::Insert && self.last_edit == Some(Edit::Insert) {
+            return;
+        }
+        self.last_edit = Some(edit);
+        self.redo.clear();
+        self.undo.push(Snapshot {
+            lines: self.lines.clone(),
+            cursor: self.cursor,
+        });
+        if self.undo.len() > MAX_HISTORY {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Replaces the text by the snapshot and returns the previous text.
+    fn restore(&mut self, snapshot: Snapshot) -> Snapshot {
+        self.anchor = None;
+        self.last_edit = None;
+        Snapshot {
+            lines: std::mem::replace(&mut self.lines, snapshot.lines),
+            cursor: std::mem::replace(&mut self.cursor, snapshot.cursor),
+        }
+    }
+
+    /// Deletes the selected text. Returns false if nothing was selected.
+    fn delete_selection(&mut self) -> bool {
+        let ((start_row, start_col), (end_row, end_col)) = match self.selection() {
+            Some(selection) => selection,
+            None => {
+                self.anchor = None;
+                return false;
+            }
+        };
+        let tail = self.lines[end_row][end_col..].to_string();
+        self.lines.drain(start_row + 1..=end_row);
+        self.lines[start_row].truncate(start_col);
+        self.lines[start_row].push_str(&tail);
+        self.cursor = (start_row, start_col);
+        self.anchor = None;
+        true
+    }
+
+    /// Rows of the text once wrapped to the width of the last render.
+    fn rows(&self) -> Vec<VisualRow> {
+        let mut rows = Vec::new();
+        for (i, line) in self.lines.iter().enumerate() {
+            let starts = match self.width {
+                Some(width) => wrap(line, width),
+                None => vec![0],
+            };
+            for (j, &start) in starts.iter().enumerate() {
+                let end = starts.get(j + 1).cloned().unwrap_or(line.len());
+                rows.push(VisualRow {
+                    line: i,
+                    start,
+                    end,
+                    last: j + 1 == starts.len(),
+                });
+            }
+        }
+        rows
+    }
+
+    /// Display column of the cursor in the given row.
+    fn column_in_row(&self, row: &VisualRow) -> usize {
+        self.lines[row.line][row.start..self.cursor.1].width()
+    }
+}
+
+/// A wrapped line of the text.
+#[derive(Debug, Clone, Copy)]
+struct VisualRow {
+    line: usize,
+    start: usize,
+    end: usize,
+    /// Whether this is the last row of the line
+    last: bool,
+}
+
+impl VisualRow {
+    fn contains(&self, (line, col): TextPosition) -> bool {
+        line == self.line && col >= self.start && (col < self.end || self.last)
+    }
+}
+
+/// Byte offsets at which each row of `line` starts once wrapped to `width` columns. Whitespace
+/// at a break stays at the end of the row, like most editors do.
+fn wrap(line: &str, width: u16) -> Vec<usize> {
+    let mut starts: Vec<usize> = vec![0];
+    let mut symbols = line.graphemes(true).map(|g| Styled(g, Style::default()));
+    let mut wrapper = WordWrapper::new(&mut symbols, width, false);
+    let mut first = true;
+    while let Some((row, _)) = wrapper.next_line() {
+        if let Some(Styled(symbol, _)) = row.first() {
+            if !first {
+                // Symbols are slices of `line`, their position gives their offset
+                let start = symbol.as_ptr() as usize - line.as_ptr() as usize;
+                let start = line[start..]
+                    .find(|c: char| !c.is_whitespace())
+                    .map_or(line.len(), |i| start + i);
+                if start < line.len() && starts.last() != Some(&start) {
+                    starts.push(start);
+                }
+            }
+            first = false;
+        }
+    }
+    starts
+}
+
+fn visual_row(rows: &[VisualRow], position: TextPosition) -> usize {
+    rows.iter().position(|r| r.contains(position)).unwrap_or(0)
+}
+
+/// Position in the given row closest to the given display column.
+fn position_in_row(
+    lines: &[String],
+    rows: &[VisualRow],
+    index: usize,
+    column: usize,
+) -> TextPosition {
+    let row = rows[index];
+    let text = &lines[row.line][row.start..row.end];
+    let mut width = 0;
+    let mut last = 0;
+    for (i, g) in text.grapheme_indices(true) {
+        if width + g.width() > column {
+            return (row.line, row.start + i);
+        }
+        width += g.width();
+        last = i;
+    }
+    if row.last {
+        (row.line, row.end)
+    } else {
+        // The end of this row is the start of the next one
+        (row.line, row.start + last)
+    }
+}
+
+fn prev_boundary(line: &str, position: usize) -> usize {
+    line[..position]
+        .grapheme_indices(true)
+        .next_back()
+        .map_or(0, |(i, _)| i)
+}
+
+fn next_boundary(line: &str, position: usize) -> usize {
+    line[position..]
+        .graphemes(true)
+        .next()
+        .map_or(position, |g| position + g.len())
+}
+
+/// A multi-line text editor. Lines are wrapped on word boundaries and the text scrolls
+/// vertically to keep the cursor visible.
+///
+/// The widget does not draw the cursor itself, place the terminal cursor at
+/// [`TextAreaState::cursor_position`] after drawing instead.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Block, Borders, TextArea};
+/// # use tui::style::{Style, Color};
+/// TextArea::default()
+///     .block(Block::default().title("Message").borders(Borders::ALL))
+///     .style(Style::default().fg(Color::White))
+///     .selection_style(Style::default().bg(Color::Blue));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TextArea<'a> {
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Style of the text
+    style: Style,
+    /// Style of the selected text
+    selection_style: Style,
+}
+
+impl<'a> TextArea<'a> {
+    pub fn block(mut self, block: Block<'a>) -> TextArea<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> TextArea<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn selection_style(mut self, style: Style) -> TextArea<'a> {
+        self.selection_style = style;
+        self
+    }
+}
+
+impl<'a> StatefulWidget for TextArea<'a> {
+    type State = TextAreaState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let theme = *buf.theme();
+        self.style = theme.text.patch(self.style);
+        self.selection_style = theme.selection.patch(self.selection_style);
+
+        let text_area = match self.block {
+            Some(ref b) => {
+                b.clone().render(area, buf);
+                b.inner(area)
+            }
+            None => area,
+        };
+
+        state.cursor_position = None;
+        if text_area.width < 1 || text_area.height < 1 {
+            return;
+        }
+
+        buf.set_style(text_area, self.style);
+
+        state.width = Some(text_area.width);
+        let rows = state.rows();
+
+        // Scroll to keep the cursor visible
+        let height = text_area.height as usize;
+        let cursor_row = visual_row(&rows, state.cursor);
+        state.offset = state.offset.min(rows.len().saturating_sub(height));
+        if cursor_row < state.offset {
+            state.offset = cursor_row;
+        } else if cursor_row >= state.offset + height {
+            state.offset = cursor_row + 1 - height;
+        }
+
+        let selection = state.selection();
+        for (y, row) in rows.iter().skip(state.offset).take(height).enumerate() {
+            let y = text_area.top() + y as u16;
+            let line = &state.lines[row.line];
+            let mut x = 0;
+            for (i, g) in line[row.start..row.end].grapheme_indices(true) {
+                let width = g.width() as u16;
+                // Graphemes wider than the area are skipped by the wrapper as well
+                if width == 0 || x + width > text_area.width {
+                    continue;
+                }
+                let position = (row.line, row.start + i);
+                let style = match selection {
+                    Some((start, end)) if position >= start && position < end => {
+                        self.selection_style
+                    }
+                    _ => self.style,
+                };
+                let left = text_area.left() + x;
+                buf.get_mut(left, y).set_symbol(g).set_style(style);
+                for x in left + 1..left + width {
+                    buf.get_mut(x, y).reset();
+                }
+                x += width;
+            }
+        }
+
+        let column = state.column_in_row(&rows[cursor_row]) as u16;
+        state.cursor_position = Some((
+            text_area.left() + column.min(text_area.width - 1),
+            text_area.top() + (cursor_row - state.offset) as u16,
+        ));
+    }
+}
@@ -0,0 +1,508 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::buffer::Buffer;
+use crate::event::{KeyCode, KeyEvent};
+use crate::layout::Rect;
+use crate::style::Style;
+use crate::symbols::line;
+use crate::text::Spans;
+use crate::widgets::{Block, ScrollbarState, StatefulWidget, Widget};
+
+/// A node of a [`Tree`].
+///
+/// The text of a node is a single line, anything converting into [`Spans`] such as a string or
+/// some spans with different styles.
+///
+/// Nodes are identified by an id, unique in the whole tree, which the [`TreeState`] uses to
+/// remember which nodes are expanded and which one is selected across renders.
+///
+/// Children can be loaded lazily: mark a node as [`expandable`](TreeItem::expandable) without
+/// giving its children, and only build them while [`TreeState::is_expanded`] is true for it.
+#[derive(Debug, Clone)]
+pub struct TreeItem<'a> {
+    id: Cow<'a, str>,
+    text: Spans<'a>,
+    children: Vec<TreeItem<'a>>,
+    expandable: bool,
+}
+
+impl<'a> TreeItem<'a> {
+    pub fn new<I, T>(id: I, text: T) -> TreeItem<'a>
+    where
+        I: Into<Cow<'a, str>>,
+        T: Into<Spans<'a>>,
+    {
+        TreeItem {
+            id: id.into(),
+            text: text.into(),
+            children: Vec::new(),
+            expandable: false,
+        }
+    }
+
+    /// Sets the children of the node, which makes it expandable.
+    pub fn children(mut self, children: Vec<TreeItem<'a>>) -> TreeItem<'a> {
+        self.expandable |= !children.is_empty();
+        self.children = children;
+        self
+    }
+
+    /// Marks the node as expandable even if its children are not given yet.
+    pub fn expandable(mut self, expandable: bool) -> TreeItem<'a> {
+        self.expandable = expandable;
+        self
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// A node of the tree as seen during the last render, stored in pre-order.
+#[derive(Debug, Clone)]
+struct Node {
+    id: String,
+    parent: Option<usize>,
+    depth: usize,
+    /// Whether this is the last child of its parent
+    last: bool,
+    expandable: bool,
+    /// Index following the last descendant of this node
+    end: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TreeState {
+    offset: usize,
+    selected: Option<String>,
+    expanded: HashSet<String>,
+    /// Area the rows were drawn to during the last render
+    area: Rect,
+    /// Nodes of the last render
+    nodes: Vec<Node>,
+}
+
+impl TreeState {
+    /// Id of the selected node.
+    pub fn selected(&self) -> Option<&str> {
+        self.selected.as_deref()
+    }
+
+    pub fn select(&mut self, id: Option<&str>) {
+        self.selected = id.map(String::from);
+        if id.is_none() {
+            self.offset = 0;
+        }
+    }
+
+    /// Index of the first visible row.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn is_expanded(&self, id: &str) -> bool {
+        self.expanded.contains(id)
+    }
+
+    pub fn expand(&mut self, id: &str) {
+        self.expanded.insert(id.to_string());
+    }
+
+    pub fn collapse(&mut self, id: &str) {
+        self.expanded.remove(id);
+    }
+
+    /// Expands a collapsed node or collapses an expanded one. Returns whether the node is now
+    /// expanded.
+    pub fn toggle(&mut self, id: &str) -> bool {
+        if self.expanded.remove(id) {
+            false
+        } else {
+            self.expanded.insert(id.to_string());
+            true
+        }
+    }
+
+    /// Returns the id of the node displayed at the given position of the screen during the last
+    /// render, if any.
+    pub fn item_at(&self, x: u16, y: u16) -> Option<&str> {
+        if !self.area.contains(x, y) {
+            return None;
+        }
+        let row = self.offset + (y - self.area.top()) as usize;
+        self.visible_nodes()
+            .get(row)
+            .map(|&i| self.nodes[i].id.as_str())
+    }
+
+    /// Selects the row below the selected one, or the first row if nothing is selected.
+    pub fn select_next(&mut self) {
+        let rows = self.visible_nodes();
+        let index = match self.selected_row(&rows) {
+            Some(row) => rows.get(row + 1).or_else(|| rows.last()),
+            None => rows.first(),
+        };
+        self.selected = index.map(|&i| self.nodes[i].id.clone());
+    }
+
+    /// Selects the row above the selected one, or the first row if nothing is selected.
+    pub fn select_previous(&mut self) {
+        let rows = self.visible_nodes();
+        let index = match self.selected_row(&rows) {
+            Some(row) => rows.get(row.saturating_sub(1)),
+            None => rows.first(),
+        };
+        self.selected = index.map(|&i| self.nodes[i].id.clone());
+    }
+
+    /// Expands the selected node if it is collapsed, otherwise selects its first child.
+    pub fn expand_selected(&mut self) {
+        let index = match self.selected_node() {
+            Some(index) => index,
+            None => return,
+        };
+        let node = &self.nodes[index];
+        if !node.expandable {
+            return;
+        }
+        if !self.expanded.contains(&node.id) {
+            self.expanded.insert(node.id.clone());
+        } else if node.end > index + 1 {
+            self.selected = Some(self.nodes[index + 1].id.clone());
+        }
+    }
+
+    /// Collapses the selected node if it is expanded, otherwise selects its parent.
+    pub fn collapse_selected(&mut self) {
+        let index = match self.selected_node() {
+            Some(index) => index,
+            None => return,
+        };
+        let node = &self.nodes[index];
+        if self.expanded.remove(&node.id) {
+            return;
+        }
+        if let Some(parent) = node.parent {
+            self.selected = Some(self.nodes[parent].id.clone());
+        }
+    }
+
+    /// Expands or collapses the selected node.
+    pub fn toggle_selected(&mut self) {
+        if let Some(id) = self.selected.clone() {
+            self.toggle(&id);
+        }
+    }
+
+    /// Applies the usual tree bindings: `Up` and `Down` move the selection, `Right` expands the
+    /// selected node or moves to its first child, `Left` collapses it or moves to its parent and
+    /// `Enter` toggles it. Returns whether the key was handled.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Up => self.select_previous(),
+            KeyCode::Down => self.select_next(),
+            KeyCode::Right => self.expand_selected(),
+            KeyCode::Left => self.collapse_selected(),
+            KeyCode::Enter => self.toggle_selected(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Scrolling position of the last render, to be displayed by a
+    /// [`Scrollbar`](crate::widgets::Scrollbar).
+    pub fn scrollbar_state(&self) -> ScrollbarState {
+        ScrollbarState {
+            content_length: self.visible_nodes().len(),
+            position: self.offset,
+            viewport_length: self.area.height as usize,
+        }
+    }
+
+    /// Indices of the nodes whose ancestors are all expanded.
+    fn visible_nodes(&self) -> Vec<usize> {
+        let mut rows = Vec::new();
+        let mut i = 0;
+        while i < self.nodes.len() {
+            rows.push(i);
+            let node = &self.nodes[i];
+            i = if self.expanded.contains(&node.id) {
+                i + 1
+            } else {
+                node.end
+            };
+        }
+        rows
+    }
+
+    fn selected_node(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+        self.nodes.iter().position(|n| &n.id == selected)
+    }
+
+    fn selected_row(&self, rows: &[usize]) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+        rows.iter().position(|&i| &self.nodes[i].id == selected)
+    }
+}
+
+/// A widget to display hierarchical data, where nodes can be expanded to show their children.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Block, Borders, Text, Tree, TreeItem};
+/// # use tui::style::{Style, Color, Modifier};
+/// let items = vec![
+///     TreeItem::new("src", Text::raw("src")).children(vec![
+///         TreeItem::new("src/lib.rs", Text::raw("lib.rs")),
+///         TreeItem::new("src/widgets", Text::raw("widgets")).expandable(true),
+///     ]),
+///     TreeItem::new("Cargo.toml", Text::raw("Cargo.toml")),
+/// ];
+/// Tree::new(items)
+///     .block(Block::default().title("Files").borders(Borders::ALL))
+///     .style(Style::default().fg(Color::White))
+///     .guide_style(Style::default().fg(Color::DarkGray))
+///     .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tree<'a> {
+    block: Option<Block<'a>>,
+    items: Vec<TreeItem<'a>>,
+    /// Base style of the widget
+    style: Style,
+    /// Style of the guide lines
+    guide_style: Style,
+    /// Symbols used to draw the guide lines
+    guide_symbols: line::Set,
+    /// Style used to render the selected node
+    highlight_style: Style,
+    /// Symbol in front of the selected node (Shift all rows to the right)
+    highlight_symbol: Option<&'a str>,
+    /// Symbol in front of expanded nodes
+    expanded_symbol: &'a str,
+    /// Symbol in front of collapsed nodes
+    collapsed_symbol: &'a str,
+}
+
+impl<'a> Tree<'a> {
+    pub fn new(items: Vec<TreeItem<'a>>) -> Tree<'a> {
+        Tree {
+            block: None,
+            items,
+            style: Default::default(),
+            guide_style: Default::default(),
+            guide_symbols: line::NORMAL,
+            highlight_style: Default::default(),
+            highlight_symbol: None,
+            expanded_symbol: "▾ ",
+            collapsed_symbol: "▸ ",
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Tree<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Tree<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn guide_style(mut self, style: Style) -> Tree<'a> {
+        self.guide_style = style;
+        self
+    }
+
+    pub fn guide_symbols(mut self, symbols: line::Set) -> Tree<'a> {
+        self.guide_symbols = symbols;
+        self
+    }
+
+    pub fn highlight_style(mut self, highlight_style: Style) -> Tree<'a> {
+        self.highlight_style = highlight_style;
+        self
+    }
+
+    pub fn highlight_symbol(mut self, highlight_symbol: &'a str) -> Tree<'a> {
+        self.highlight_symbol = Some(highlight_symbol);
+        self
+    }
+
+    /// Symbols in front of expanded and collapsed nodes. Leaves get as many blank columns.
+    pub fn node_symbols(mut self, expanded: &'a str, collapsed: &'a str) -> Tree<'a> {
+        self.expanded_symbol = expanded;
+        self.collapsed_symbol = collapsed;
+        self
+    }
+}
+
+/// Lists the nodes of `items` and their descendants in pre-order.
+fn flatten<'a, 'b>(
+    items: &'b [TreeItem<'a>],
+    parent: Option<usize>,
+    depth: usize,
+    nodes: &mut Vec<Node>,
+    texts: &mut Vec<&'b Spans<'a>>,
+) {
+    for (i, item) in items.iter().enumerate() {
+        let index = nodes.len();
+        nodes.push(Node {
+            id: item.id.to_string(),
+            parent,
+            depth,
+            last: i + 1 == items.len(),
+            expandable: item.expandable,
+            end: 0,
+        });
+        texts.push(&item.text);
+        flatten(&item.children, Some(index), depth + 1, nodes, texts);
+        nodes[index].end = nodes.len();
+    }
+}
+
+impl<'a> StatefulWidget for Tree<'a> {
+    type State = TreeState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let theme = *buf.theme();
+        self.style = theme.text.patch(self.style);
+        self.guide_style = theme.guide.patch(self.guide_style);
+        self.highlight_style = theme.selection.patch(self.highlight_style);
+
+        let tree_area = match self.block {
+            Some(ref b) => {
+                b.clone().render(area, buf);
+                b.inner(area)
+            }
+            None => area,
+        };
+
+        let mut texts = Vec::new();
+        state.nodes.clear();
+        flatten(&self.items, None, 0, &mut state.nodes, &mut texts);
+        state.area = tree_area;
+
+        if tree_area.width < 1 || tree_area.height < 1 {
+            return;
+        }
+
+        buf.set_style(tree_area, self.style);
+
+        // Select the closest visible ancestor when the selected node is hidden
+        if let Some(mut index) = state.selected_node() {
+            let mut ancestor = state.nodes[index].parent;
+            while let Some(parent) = ancestor {
+                if !state.expanded.contains(&state.nodes[parent].id) {
+                    index = parent;
+                }
+                ancestor = state.nodes[parent].parent;
+            }
+            state.selected = Some(state.nodes[index].id.clone());
+        }
+
+        let rows = state.visible_nodes();
+        let selected = state.selected_row(&rows);
+        let height = tree_area.height as usize;
+
+        // Make sure the tree shows the selected node
+        state.offset = state.offset.min(rows.len().saturating_sub(height));
+        if let Some(selected) = selected {
+            if selected >= state.offset + height {
+                state.offset = selected + 1 - height;
+            } else if selected < state.offset {
+                state.offset = selected;
+            }
+        }
+
+        let highlight_symbol = self.highlight_symbol.unwrap_or("");
+        let blank_symbol = " ".repeat(highlight_symbol.width());
+        let leaf_symbol = " ".repeat(
+            self.expanded_symbol
+                .width()
+                .max(self.collapsed_symbol.width()),
+        );
+        let symbols = &self.guide_symbols;
+
+        for (i, &index) in rows.iter().enumerate().skip(state.offset).take(height) {
+            let node = &state.nodes[index];
+            let y = tree_area.top() + (i - state.offset) as u16;
+            let is_selected = selected == Some(i);
+            let style = if is_selected {
+                self.highlight_style
+            } else {
+                self.style
+            };
+            let right = tree_area.right();
+            let width = |x: u16| (right - x) as usize;
+
+            let mut x = tree_area.left();
+            if is_selected {
+                x = buf.set_stringn(x, y, highlight_symbol, width(x), style).0;
+            } else {
+                x = buf.set_stringn(x, y, &blank_symbol, width(x), self.style).0;
+            }
+
+            // Guides of the ancestors, from the root down, then of the node itself
+            let mut guides = Vec::with_capacity(node.depth);
+            if node.depth > 0 {
+                guides.push(if node.last {
+                    symbols.bottom_left
+                } else {
+                    symbols.vertical_right
+                });
+                let mut ancestor = node.parent;
+                while let Some(parent) = ancestor {
+                    let parent = &state.nodes[parent];
+                    if parent.depth > 0 {
+                        guides.push(if parent.last { " " } else { symbols.vertical });
+                    }
+                    ancestor = parent.parent;
+                }
+            }
+            for (level, guide) in guides.iter().rev().enumerate() {
+                let fill = if level + 1 == guides.len() {
+                    symbols.horizontal
+                } else {
+                    " "
+                };
+                x = buf.set_stringn(x, y, guide, width(x), self.guide_style).0;
+                x = buf.set_stringn(x, y, fill, width(x), self.guide_style).0;
+            }
+
+            let node_symbol = if !node.expandable {
+                leaf_symbol.as_str()
+            } else if state.expanded.contains(&node.id) {
+                self.expanded_symbol
+            } else {
+                self.collapsed_symbol
+            };
+            x = buf.set_stringn(x, y, node_symbol, width(x), style).0;
+
+            // The highlight is applied on top of the styles of the spans
+            let mut text = Spans::from(texts[index]);
+            if is_selected {
+                text.0
+                    .iter_mut()
+                    .for_each(|span| span.style = span.style.patch(style));
+            }
+            buf.set_spans(x, y, &text, width(x) as u16, style);
+        }
+
+        if let Some(ref b) = self.block {
+            b.render_scrollbar(area, buf, &state.scrollbar_state());
+        }
+    }
+}
+
+impl<'a> Widget for Tree<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TreeState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
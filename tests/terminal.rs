@@ -1,6 +1,13 @@
+use std::panic;
+use std::time::Duration;
 use tui::{
-    backend::{Backend, TestBackend},
-    Terminal,
+    backend::{Backend, CursorShape, TestBackend},
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::Style,
+    text::Spans,
+    widgets::{Block, Borders, Paragraph},
+    Frame, Terminal, TerminalOptions, Viewport,
 };
 
 #[test]
@@ -11,3 +18,445 @@ fn terminal_buffer_size_should_be_limited() {
     assert_eq!(size.width, 255);
     assert_eq!(size.height, 255);
 }
+
+#[test]
+fn terminal_inline_viewport_should_scroll_to_make_room() {
+    let mut backend = TestBackend::new(10, 5);
+    let mut prompt = Cell::default();
+    prompt.set_symbol("$");
+    backend.draw(vec![(0, 2, &prompt)].into_iter()).unwrap();
+    backend.set_cursor(0, 3).unwrap();
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(3),
+            ..TerminalOptions::default()
+        },
+    )
+    .unwrap();
+    terminal
+        .draw(|mut f| {
+            let size = f.size();
+            assert_eq!(size, Rect::new(0, 2, 10, 3));
+            let block = Block::default().title("Inline").borders(Borders::ALL);
+            f.render_widget(block, size);
+        })
+        .unwrap();
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "          ",
+        "$         ",
+        "┌Inline──┐",
+        "│        │",
+        "└────────┘",
+    ]));
+}
+
+#[test]
+fn terminal_inline_viewport_should_not_scroll_with_enough_room() {
+    let mut backend = TestBackend::new(10, 6);
+    backend.set_cursor(0, 1).unwrap();
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(2),
+            ..TerminalOptions::default()
+        },
+    )
+    .unwrap();
+    terminal
+        .draw(|mut f| {
+            let size = f.size();
+            assert_eq!(size, Rect::new(0, 1, 10, 2));
+            f.render_widget(Block::default().borders(Borders::ALL), size);
+        })
+        .unwrap();
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "          ",
+        "┌────────┐",
+        "└────────┘",
+        "          ",
+        "          ",
+        "          ",
+    ]));
+}
+
+#[test]
+fn terminal_insert_before_should_push_lines_above_the_viewport() {
+    let mut backend = TestBackend::new(10, 5);
+    backend.set_cursor(0, 1).unwrap();
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(2),
+            ..TerminalOptions::default()
+        },
+    )
+    .unwrap();
+    let draw = |terminal: &mut Terminal<TestBackend>, title| {
+        terminal
+            .draw(|mut f| {
+                let block = Block::default().title(title).borders(Borders::ALL);
+                f.render_widget(block, f.size());
+            })
+            .unwrap();
+    };
+    draw(&mut terminal, "A");
+    terminal
+        .insert_before(1, |buf| {
+            buf.set_string(0, 0, "log 1", Style::default());
+        })
+        .unwrap();
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "          ",
+        "log 1     ",
+        "┌A───────┐",
+        "└────────┘",
+        "          ",
+    ]));
+
+    // Not enough room left, the screen has to be scrolled
+    terminal
+        .insert_before(3, |buf| {
+            buf.set_string(0, 0, "log 2", Style::default());
+            buf.set_string(0, 1, "log 3", Style::default());
+            buf.set_string(0, 2, "log 4", Style::default());
+        })
+        .unwrap();
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "log 2     ",
+        "log 3     ",
+        "log 4     ",
+        "┌A───────┐",
+        "└────────┘",
+    ]));
+
+    // The next frame is diffed against the one drawn at the new position
+    draw(&mut terminal, "B");
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "log 2     ",
+        "log 3     ",
+        "log 4     ",
+        "┌B───────┐",
+        "└────────┘",
+    ]));
+}
+
+#[test]
+fn terminal_insert_before_should_scroll_lines_out_of_a_viewport_filling_the_screen() {
+    let mut terminal = Terminal::with_options(
+        TestBackend::new(10, 3),
+        TerminalOptions {
+            viewport: Viewport::Inline(3),
+            ..TerminalOptions::default()
+        },
+    )
+    .unwrap();
+    terminal
+        .draw(|mut f| {
+            let block = Block::default().title("A").borders(Borders::ALL);
+            f.render_widget(block, f.size());
+        })
+        .unwrap();
+    terminal
+        .insert_before(2, |buf| {
+            buf.set_string(0, 0, "log 1", Style::default());
+            buf.set_string(0, 1, "log 2", Style::default());
+        })
+        .unwrap();
+    assert_eq!(
+        terminal.backend().scrollback(),
+        Buffer::with_lines(vec!["log 1     ", "log 2     "])
+    );
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "┌A───────┐",
+        "│        │",
+        "└────────┘",
+    ]));
+}
+
+#[test]
+fn terminal_should_track_alternate_screen_and_raw_mode() {
+    let backend = TestBackend::new(10, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.enable_raw_mode().unwrap();
+    terminal.enter_alternate_screen().unwrap();
+    assert!(terminal.backend().is_raw_mode());
+    assert!(terminal.backend().is_alternate_screen());
+    terminal.leave_alternate_screen().unwrap();
+    terminal.disable_raw_mode().unwrap();
+    assert!(!terminal.backend().is_raw_mode());
+    assert!(!terminal.backend().is_alternate_screen());
+}
+
+#[test]
+fn terminal_should_be_restored_when_dropped() {
+    let backend = TestBackend::new(10, 5);
+    let modes = backend.modes();
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.enable_raw_mode().unwrap();
+    terminal.enter_alternate_screen().unwrap();
+    drop(terminal);
+    assert!(!modes.is_raw_mode());
+    assert!(!modes.is_alternate_screen());
+}
+
+#[test]
+fn terminal_should_be_restored_by_the_panic_hook_when_enabled() {
+    let backend = TestBackend::new(10, 5);
+    let modes = backend.modes();
+    let options = TerminalOptions {
+        restore_on_panic: true,
+        ..TerminalOptions::default()
+    };
+    let mut terminal = Terminal::with_options(backend, options).unwrap();
+    terminal.enable_raw_mode().unwrap();
+    terminal.enter_alternate_screen().unwrap();
+    let result = panic::catch_unwind(|| panic!("rendering failed"));
+    assert!(result.is_err());
+    assert!(!modes.is_raw_mode());
+    assert!(!modes.is_alternate_screen());
+}
+
+#[test]
+fn terminal_hit_map_should_resolve_positions_to_the_last_frame_widgets() {
+    let backend = TestBackend::new(10, 10);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|mut f| {
+            f.render_widget_with_id(Block::default(), Rect::new(0, 0, 10, 10), 1);
+            f.render_widget_with_id(Block::default(), Rect::new(2, 2, 4, 4), 2);
+        })
+        .unwrap();
+
+    let hit = terminal.hit_map().hit(3, 4).unwrap();
+    assert_eq!(hit.id, 2);
+    assert_eq!(hit.area, Rect::new(2, 2, 4, 4));
+    assert_eq!((hit.x, hit.y), (1, 2));
+    assert_eq!(terminal.hit_map().hit(8, 8).map(|h| h.id), Some(1));
+
+    terminal
+        .draw(|mut f| f.render_widget(Block::default(), f.size()))
+        .unwrap();
+    assert!(terminal.hit_map().is_empty());
+}
+
+#[test]
+fn terminal_should_draw_frames_within_synchronized_updates_when_enabled() {
+    let draw = |terminal: &mut Terminal<TestBackend>, title| {
+        terminal
+            .draw(|mut f| {
+                let block = Block::default().title(title).borders(Borders::ALL);
+                f.render_widget(block, f.size())
+            })
+            .unwrap();
+    };
+
+    let mut terminal = Terminal::new(TestBackend::new(10, 3)).unwrap();
+    draw(&mut terminal, "a");
+    assert_eq!(terminal.backend().synchronized_updates(), 0);
+    assert_eq!(terminal.backend().unsynchronized_draws(), 1);
+
+    let backend = TestBackend::new(10, 5);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(2),
+            synchronized_output: true,
+            ..TerminalOptions::default()
+        },
+    )
+    .unwrap();
+    draw(&mut terminal, "a");
+    draw(&mut terminal, "b");
+    terminal
+        .insert_before(1, |buf| {
+            buf.set_string(0, 0, "log", Style::default());
+        })
+        .unwrap();
+    // The viewport is cleared when the terminal is created, then two frames and a line are drawn
+    assert_eq!(terminal.backend().synchronized_updates(), 4);
+    assert_eq!(terminal.backend().unsynchronized_draws(), 0);
+}
+
+#[test]
+fn terminal_should_scroll_lines_which_moved_since_the_previous_frame() {
+    let log = [
+        "alpha", "bravo", "charlie", "delta", "echo", "foxtrot", "golf", "hotel",
+    ];
+    let draw = |terminal: &mut Terminal<TestBackend>, offset: u16| {
+        terminal
+            .draw(|mut f| {
+                let lines: Vec<Spans> = log.iter().map(|&l| Spans::from(l)).collect();
+                let paragraph = Paragraph::new(lines)
+                    .block(Block::default().borders(Borders::ALL))
+                    .scroll(offset);
+                f.render_widget(paragraph, f.size());
+            })
+            .unwrap();
+    };
+
+    let mut terminal = Terminal::with_options(
+        TestBackend::new(12, 6),
+        TerminalOptions {
+            scroll_regions: true,
+            ..TerminalOptions::default()
+        },
+    )
+    .unwrap();
+    draw(&mut terminal, 0);
+    let drawn_cells = terminal.backend().drawn_cells();
+    draw(&mut terminal, 2);
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "┌──────────┐",
+        "│charlie   │",
+        "│delta     │",
+        "│echo      │",
+        "│foxtrot   │",
+        "└──────────┘",
+    ]));
+    // Only the two lines scrolled into view are drawn
+    assert_eq!(
+        terminal.backend().drawn_cells() - drawn_cells,
+        2 + 4 + 2 + 7
+    );
+
+    draw(&mut terminal, 1);
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "┌──────────┐",
+        "│bravo     │",
+        "│charlie   │",
+        "│delta     │",
+        "│echo      │",
+        "└──────────┘",
+    ]));
+
+    // Without the option, the moved lines are drawn again
+    let mut terminal = Terminal::new(TestBackend::new(12, 6)).unwrap();
+    draw(&mut terminal, 0);
+    let drawn_cells = terminal.backend().drawn_cells();
+    draw(&mut terminal, 2);
+    assert!(terminal.backend().drawn_cells() - drawn_cells > 2 + 4 + 2 + 7);
+}
+
+#[test]
+fn terminal_should_show_the_cursor_requested_by_the_frame() {
+    let mut terminal = Terminal::with_options(
+        TestBackend::new(10, 5),
+        TerminalOptions {
+            viewport: Viewport::Inline(2),
+            ..TerminalOptions::default()
+        },
+    )
+    .unwrap();
+    terminal
+        .draw(|mut f| {
+            assert_eq!(f.size(), Rect::new(0, 0, 10, 2));
+            f.set_cursor(3, 1);
+            f.set_cursor_shape(CursorShape::SteadyBar);
+        })
+        .unwrap();
+    assert_eq!(terminal.backend().cursor_position(), Some((3, 1)));
+    assert_eq!(terminal.backend().cursor_shape(), CursorShape::SteadyBar);
+
+    // The cursor moves down with the viewport when lines are inserted above it
+    terminal.insert_before(1, |_| {}).unwrap();
+    assert_eq!(terminal.backend().cursor_position(), Some((3, 2)));
+
+    terminal.draw(|_| {}).unwrap();
+    assert_eq!(terminal.backend().cursor_position(), None);
+    terminal.draw(|mut f| f.set_cursor(0, 2)).unwrap();
+    assert_eq!(terminal.backend().cursor_position(), Some((0, 2)));
+    assert_eq!(terminal.backend().cursor_shape(), CursorShape::Default);
+}
+
+#[test]
+fn terminal_draw_should_return_the_completed_frame() {
+    let mut terminal = Terminal::new(TestBackend::new(6, 3)).unwrap();
+    let frame = terminal
+        .draw(|mut f| f.render_widget(Block::default().borders(Borders::ALL), f.size()))
+        .unwrap();
+    assert_eq!(frame.area, Rect::new(0, 0, 6, 3));
+    assert_eq!(
+        *frame.buffer,
+        Buffer::with_lines(vec!["┌────┐", "│    │", "└────┘"])
+    );
+}
+
+#[test]
+fn terminal_try_draw_should_drop_the_frame_when_rendering_fails() {
+    let mut terminal = Terminal::new(TestBackend::new(6, 3)).unwrap();
+    let (_, size) = terminal
+        .try_draw(|f| Ok::<_, std::io::Error>(f.size()))
+        .unwrap();
+    assert_eq!(size, Rect::new(0, 0, 6, 3));
+
+    let result = terminal.try_draw(|mut f| {
+        f.render_widget(Block::default().borders(Borders::ALL), f.size());
+        Err::<(), _>(std::io::Error::new(
+            std::io::ErrorKind::Interrupted,
+            "abort",
+        ))
+    });
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::Interrupted);
+    assert_eq!(terminal.backend().drawn_cells(), 0);
+
+    // The next frame starts from a blank buffer
+    let frame = terminal.draw(|_| {}).unwrap();
+    assert_eq!(*frame.buffer, Buffer::empty(Rect::new(0, 0, 6, 3)));
+}
+
+#[test]
+fn terminal_should_not_write_anything_when_the_frame_did_not_change() {
+    let mut terminal = Terminal::new(TestBackend::new(6, 3)).unwrap();
+    let render = |x| {
+        move |mut f: Frame<TestBackend>| {
+            f.render_widget(Block::default().borders(Borders::ALL), f.size());
+            f.set_cursor(x, 1);
+        }
+    };
+    terminal.draw(render(1)).unwrap();
+    let (drawn_cells, flushes) = (
+        terminal.backend().drawn_cells(),
+        terminal.backend().flushes(),
+    );
+    terminal.draw(render(1)).unwrap();
+    assert_eq!(terminal.backend().drawn_cells(), drawn_cells);
+    assert_eq!(terminal.backend().flushes(), flushes);
+
+    // Moving the cursor is a change
+    terminal.draw(render(2)).unwrap();
+    assert_eq!(terminal.backend().drawn_cells(), drawn_cells);
+    assert_eq!(terminal.backend().flushes(), flushes + 1);
+    assert_eq!(terminal.backend().cursor_position(), Some((2, 1)));
+}
+
+#[test]
+fn terminal_should_draw_requested_frames_at_the_maximum_frame_rate() {
+    let mut terminal = Terminal::with_options(
+        TestBackend::new(6, 3),
+        TerminalOptions {
+            max_fps: Some(1),
+            ..TerminalOptions::default()
+        },
+    )
+    .unwrap();
+    // The first frame is always needed
+    assert_eq!(
+        terminal.time_until_next_frame(),
+        Some(Duration::from_secs(0))
+    );
+    assert!(terminal.draw_if_needed(|_| {}).unwrap().is_some());
+    assert_eq!(terminal.time_until_next_frame(), None);
+    assert!(terminal.draw_if_needed(|_| {}).unwrap().is_none());
+
+    let handle = terminal.redraw_handle();
+    handle.request_redraw();
+    handle.clone().request_redraw();
+    let wait = terminal.time_until_next_frame().unwrap();
+    assert!(wait > Duration::from_secs(0) && wait <= Duration::from_secs(1));
+    assert!(terminal.draw_if_needed(|_| {}).unwrap().is_none());
+
+    // An explicit draw satisfies the pending requests
+    terminal.draw(|_| {}).unwrap();
+    assert_eq!(terminal.time_until_next_frame(), None);
+}
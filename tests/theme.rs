@@ -0,0 +1,77 @@
+use tui::backend::TestBackend;
+use tui::buffer::Buffer;
+use tui::layout::Rect;
+use tui::style::{Color, Modifier, Style};
+use tui::theme::{Error, Theme};
+use tui::widgets::{Block, Borders, List, ListState};
+use tui::Terminal;
+
+#[test]
+fn theme_styles_widgets_under_their_own_styles() {
+    let backend = TestBackend::new(8, 4);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let theme: Theme = "
+        border = fg=blue
+        title = mod=bold
+        selection = mod=reversed
+    "
+    .parse()
+    .unwrap();
+    terminal.set_theme(theme);
+    assert_eq!(terminal.theme(), &theme);
+
+    let mut state = ListState::default();
+    state.select(Some(1));
+    terminal
+        .draw(|mut f| {
+            let list = List::new(["a", "b"].iter())
+                .block(
+                    Block::default()
+                        .title("T")
+                        .borders(Borders::ALL)
+                        .title_style(Style::default().fg(Color::Red)),
+                )
+                .highlight_style(Style::default().fg(Color::Green));
+            f.render_stateful_widget(list, Rect::new(0, 0, 8, 4), &mut state);
+        })
+        .unwrap();
+
+    let mut expected = Buffer::with_lines(vec!["┌T─────┐", "│a     │", "│b     │", "└──────┘"]);
+    for x in 0..8 {
+        for y in 0..4 {
+            if x == 0 || x == 7 || y == 0 || y == 3 {
+                expected.get_mut(x, y).set_fg(Color::Blue);
+            }
+        }
+    }
+    expected
+        .get_mut(1, 0)
+        .set_fg(Color::Red)
+        .set_modifier(Modifier::BOLD);
+    expected
+        .get_mut(1, 2)
+        .set_fg(Color::Green)
+        .set_modifier(Modifier::REVERSED);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn theme_reports_invalid_lines() {
+    let error = |s: &str| s.parse::<Theme>().unwrap_err().to_string();
+    assert_eq!(
+        error("text = fg=red\nborder"),
+        "line 2: expected `role = style`"
+    );
+    assert_eq!(
+        error("\nborders = fg=red"),
+        "line 2: unknown role `borders`"
+    );
+    assert_eq!(
+        error("# comment\ntitle = mod=fat"),
+        "line 2: invalid modifier `fat`"
+    );
+    assert!(matches!(
+        Theme::load("/nonexistent/theme"),
+        Err(Error::Io(_))
+    ));
+}
@@ -2,6 +2,7 @@ use tui::backend::TestBackend;
 use tui::buffer::Buffer;
 use tui::layout::Rect;
 use tui::style::{Color, Style};
+use tui::text::{Span, Spans};
 use tui::widgets::{Block, Borders};
 use tui::Terminal;
 
@@ -43,3 +44,30 @@ fn widgets_block_renders() {
     }
     terminal.backend().assert_buffer(&expected);
 }
+
+#[test]
+fn widgets_block_renders_styled_title() {
+    let backend = TestBackend::new(12, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|mut f| {
+            let title = Spans::from(vec![
+                Span::raw("Log "),
+                Span::styled("ERR", Style::default().fg(Color::Red)),
+            ]);
+            let block = Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .title_style(Style::default().fg(Color::LightBlue));
+            f.render_widget(block, Rect::new(0, 0, 12, 3));
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["┌Log ERR───┐", "│          │", "└──────────┘"]);
+    for x in 1..5 {
+        expected.get_mut(x, 0).set_fg(Color::LightBlue);
+    }
+    for x in 5..8 {
+        expected.get_mut(x, 0).set_fg(Color::Red);
+    }
+    terminal.backend().assert_buffer(&expected);
+}
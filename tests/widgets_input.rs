@@ -0,0 +1,92 @@
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    event::{KeyCode, KeyEvent, KeyModifiers},
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, Input, InputState, StatefulWidget},
+    Terminal,
+};
+
+#[test]
+fn widgets_input_should_edit_graphemes() {
+    let mut state = InputState::new("héllo wörld");
+    state.handle_key(&KeyEvent::from(KeyCode::Backspace));
+    assert_eq!(state.value(), "héllo wörl");
+
+    state.handle_key(&KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL));
+    assert_eq!(state.cursor(), "héllo ".len());
+    state.handle_key(&KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL));
+    assert_eq!(state.cursor(), 0);
+
+    state.handle_key(&KeyEvent::from(KeyCode::Right));
+    state.handle_key(&KeyEvent::from(KeyCode::Delete));
+    assert_eq!(state.value(), "hllo wörl");
+
+    // A flag is a single grapheme made of two code points
+    state.move_end(false);
+    state.insert_str("🇫🇷");
+    state.handle_key(&KeyEvent::from(KeyCode::Backspace));
+    assert_eq!(state.value(), "hllo wörl");
+
+    state.handle_key(&KeyEvent::from(KeyCode::Home));
+    state.handle_key(&KeyEvent::new(KeyCode::Right, KeyModifiers::SHIFT));
+    state.handle_key(&KeyEvent::new(KeyCode::Right, KeyModifiers::SHIFT));
+    assert_eq!(state.selected_text(), Some("hl"));
+    state.handle_key(&KeyEvent::from(KeyCode::Char('H')));
+    assert_eq!(state.value(), "Hlo wörl");
+    assert_eq!(state.selection(), None);
+
+    assert!(!state.handle_key(&KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)));
+}
+
+#[test]
+fn widgets_input_should_scroll_to_the_cursor() {
+    let mut state = InputState::new("一二三四五");
+    let draw = |state: &mut InputState| {
+        let area = Rect::new(0, 0, 8, 3);
+        let mut buffer = Buffer::empty(area);
+        let input = Input::default().block(Block::default().borders(Borders::ALL));
+        StatefulWidget::render(input, area, &mut buffer, state);
+        buffer
+    };
+
+    // The cursor is at the end, the first two characters are scrolled out and the third one
+    // is cut by the left edge
+    let buffer = draw(&mut state);
+    assert_eq!(
+        buffer,
+        Buffer::with_lines(vec!["┌──────┐", "│ 四五 │", "└──────┘"])
+    );
+    assert_eq!(state.cursor_position(), Some((6, 1)));
+
+    state.move_home(false);
+    let buffer = draw(&mut state);
+    assert_eq!(
+        buffer,
+        Buffer::with_lines(vec!["┌──────┐", "│一二三│", "└──────┘"])
+    );
+    assert_eq!(state.cursor_position(), Some((1, 1)));
+}
+
+#[test]
+fn widgets_input_should_mask_and_highlight_the_selection() {
+    let backend = TestBackend::new(6, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = InputState::new("secret");
+    state.set_mask(Some('*'));
+    state.move_left(true);
+    state.move_left(true);
+    terminal
+        .draw(|mut f| {
+            let input = Input::default().selection_style(Style::default().bg(Color::Blue));
+            f.render_stateful_widget(input, Rect::new(0, 0, 6, 1), &mut state);
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["******"]);
+    for x in 4..6 {
+        expected.get_mut(x, 0).set_bg(Color::Blue);
+    }
+    terminal.backend().assert_buffer(&expected);
+    assert_eq!(state.cursor_position(), Some((4, 0)));
+}
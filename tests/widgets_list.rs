@@ -1,10 +1,12 @@
 use tui::{
     backend::TestBackend,
     buffer::Buffer,
-    layout::Rect,
-    style::{Color, Style},
+    event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
+    layout::{Corner, Rect},
+    style::{Color, Modifier, Style},
     symbols,
-    widgets::{Block, Borders, List, ListState, Text},
+    text::{self, Span, Spans},
+    widgets::{Block, Borders, List, ListState, StatefulWidget, Text},
     Terminal,
 };
 
@@ -81,3 +83,165 @@ fn widgets_list_should_truncate_items() {
         terminal.backend().assert_buffer(&case.expected);
     }
 }
+
+#[test]
+fn widgets_list_should_resolve_mouse_positions_to_items() {
+    let backend = TestBackend::new(10, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = ListState::default();
+    let draw = |terminal: &mut Terminal<TestBackend>, state: &mut ListState, corner| {
+        terminal
+            .draw(|mut f| {
+                let items = (0..10).map(|i| Text::raw(format!("Item {}", i)));
+                let list = List::new(items)
+                    .block(Block::default().borders(Borders::ALL))
+                    .highlight_symbol(">> ")
+                    .start_corner(corner);
+                f.render_stateful_widget(list, Rect::new(0, 0, 10, 5), state);
+            })
+            .unwrap();
+    };
+
+    draw(&mut terminal, &mut state, Corner::TopLeft);
+    assert_eq!(state.item_at(0, 1), None);
+    assert_eq!(state.item_at(1, 1), Some(0));
+    assert_eq!(state.item_at(8, 3), Some(2));
+    assert_eq!(state.item_at(1, 4), None);
+
+    let click = MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: 4,
+        row: 2,
+        modifiers: KeyModifiers::empty(),
+    };
+    assert!(state.handle_mouse_event(&click));
+    assert_eq!(state.selected(), Some(1));
+
+    let scroll = MouseEvent {
+        kind: MouseEventKind::ScrollDown,
+        ..click
+    };
+    for _ in 0..20 {
+        state.handle_mouse_event(&scroll);
+    }
+    // The offset stops at the last page and the selection follows the visible items
+    assert_eq!(state.offset(), 7);
+    assert_eq!(state.selected(), Some(7));
+    draw(&mut terminal, &mut state, Corner::TopLeft);
+    assert_eq!(state.offset(), 7);
+    assert_eq!(state.item_at(1, 3), Some(9));
+
+    state.select(None);
+    draw(&mut terminal, &mut state, Corner::BottomLeft);
+    assert_eq!(state.item_at(1, 3), Some(0));
+    assert_eq!(state.item_at(1, 1), Some(2));
+}
+
+#[test]
+fn widgets_list_should_clamp_the_offset_when_the_list_shrinks() {
+    let backend = TestBackend::new(10, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = ListState::default();
+    let draw = |terminal: &mut Terminal<TestBackend>, state: &mut ListState, count| {
+        terminal
+            .draw(|mut f| {
+                let items: Vec<Text> = (0..count)
+                    .map(|i| Text::raw(format!("Item {}", i)))
+                    .collect();
+                f.render_stateful_widget(List::new(items.into_iter()), f.size(), state);
+            })
+            .unwrap();
+    };
+    draw(&mut terminal, &mut state, 10);
+    state.scroll_down(7);
+    assert_eq!(state.offset(), 7);
+
+    draw(&mut terminal, &mut state, 4);
+    assert_eq!(state.offset(), 1);
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "Item 1    ",
+        "Item 2    ",
+        "Item 3    ",
+    ]));
+}
+
+#[test]
+fn widgets_list_should_only_iterate_up_to_the_last_visible_item() {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+    let mut state = ListState::default();
+    // An endless iterator of unknown size
+    let items = (0..).filter(|i| i % 2 == 0).map(|i| format!("Item {}", i));
+    List::new(items).render(buffer.area, &mut buffer, &mut state);
+    assert_eq!(
+        buffer,
+        Buffer::with_lines(vec!["Item 0    ", "Item 2    ", "Item 4    "])
+    );
+    // The list can be scrolled past the visible items one at a time
+    assert_eq!(state.scrollbar_state().content_length, 4);
+    state.scroll_down(5);
+    assert_eq!(state.offset(), 1);
+}
+
+#[test]
+fn widgets_list_should_mix_styles_in_an_item() {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|mut f| {
+            let items = vec![
+                text::Text::from(Spans::from(vec![
+                    Span::styled("ok", Style::default().fg(Color::Green)),
+                    Span::raw(" done"),
+                ])),
+                // Only the first line is displayed
+                text::Text::raw("pending\nsecond line"),
+            ];
+            let list = List::new(items.into_iter()).style(Style::default().fg(Color::White));
+            f.render_widget(list, Rect::new(0, 0, 10, 2));
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["ok done   ", "pending   "]);
+    expected.set_style(expected.area, Style::default().fg(Color::White));
+    for x in 0..2 {
+        expected.get_mut(x, 0).set_fg(Color::Green);
+    }
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_list_should_patch_item_styles_with_the_highlight_style() {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = ListState::default();
+    state.select(Some(0));
+    terminal
+        .draw(|mut f| {
+            let items = vec![
+                Span::styled(
+                    "error",
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("info"),
+            ];
+            let list = List::new(items.into_iter())
+                .style(Style::default().bg(Color::Blue))
+                .highlight_style(
+                    Style::default()
+                        .add_modifier(Modifier::REVERSED)
+                        .remove_modifier(Modifier::BOLD),
+                )
+                .highlight_symbol(">");
+            f.render_stateful_widget(list, Rect::new(0, 0, 10, 2), &mut state);
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec![">error    ", " info     "]);
+    expected.set_style(expected.area, Style::default().bg(Color::Blue));
+    expected.get_mut(0, 0).set_modifier(Modifier::REVERSED);
+    for x in 1..6 {
+        expected
+            .get_mut(x, 0)
+            .set_fg(Color::Red)
+            .set_modifier(Modifier::REVERSED);
+    }
+    terminal.backend().assert_buffer(&expected);
+}
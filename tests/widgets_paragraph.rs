@@ -2,7 +2,8 @@ use tui::{
     backend::TestBackend,
     buffer::Buffer,
     layout::Alignment,
-    widgets::{Block, Borders, Paragraph, Text},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarState, Text},
     Terminal,
 };
 
@@ -139,3 +140,82 @@ fn widgets_paragraph_renders_mixed_width_graphemes() {
     ]);
     terminal.backend().assert_buffer(&expected);
 }
+
+#[test]
+fn widgets_paragraph_should_draw_a_scrollbar_in_its_block() {
+    let backend = TestBackend::new(10, 6);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = ScrollbarState::default();
+
+    terminal
+        .draw(|mut f| {
+            let size = f.size();
+            let text = [Text::raw("1\n2\n3\n4\n5\n6\n7\n8\n")];
+            let paragraph = Paragraph::new(text.iter())
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .scrollbar(Scrollbar::default()),
+                )
+                .scroll(4);
+            f.render_stateful_widget(paragraph, size, &mut state);
+        })
+        .unwrap();
+
+    assert_eq!(
+        state,
+        ScrollbarState {
+            content_length: 8,
+            position: 4,
+            viewport_length: 4,
+        }
+    );
+    let expected = Buffer::with_lines(vec![
+        "┌────────┐",
+        "│5       │",
+        "│6       │",
+        "│7       █",
+        "│8       █",
+        "└────────┘",
+    ]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_paragraph_can_parse_markup() {
+    let test_case = |paragraph: Paragraph, expected| {
+        let backend = TestBackend::new(12, 2);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|mut f| {
+                let size = f.size();
+                let paragraph = paragraph.style(Style::default().bg(Color::Blue));
+                f.render_widget(paragraph, size);
+            })
+            .unwrap();
+        terminal.backend().assert_buffer(&expected);
+    };
+
+    let mut expected = Buffer::with_lines(vec!["a bold \\{x} ", "and red     "]);
+    expected.set_background(expected.area, Color::Blue);
+    for x in 2..11 {
+        expected.get_mut(x, 0).set_modifier(Modifier::BOLD);
+    }
+    for x in 0..3 {
+        expected.get_mut(x, 1).set_modifier(Modifier::BOLD);
+    }
+    for x in 4..7 {
+        expected.get_mut(x, 1).set_fg(Color::Red);
+    }
+    test_case(
+        Paragraph::try_markup("a {mod=bold bold \\\\\\{x\\}\nand} {fg=red red}").unwrap(),
+        expected,
+    );
+
+    // Without markup, the text is drawn as is
+    let mut expected = Buffer::with_lines(vec!["a {bold x}\\ ", "            "]);
+    expected.set_background(expected.area, Color::Blue);
+    test_case(Paragraph::new("a {bold x}\\ "), expected);
+
+    assert!(Paragraph::try_markup("a {bold x}} ").is_err());
+}
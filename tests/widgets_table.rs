@@ -1,7 +1,9 @@
 use tui::backend::TestBackend;
 use tui::buffer::Buffer;
-use tui::layout::Constraint;
-use tui::widgets::{Block, Borders, Row, Table};
+use tui::event::{KeyModifiers, MouseEvent, MouseEventKind};
+use tui::layout::{Constraint, Rect};
+use tui::text::Span;
+use tui::widgets::{Block, Borders, Row, Table, TableState};
 use tui::Terminal;
 
 #[test]
@@ -416,3 +418,96 @@ fn widgets_table_columns_widths_can_use_mixed_constraints() {
         ]),
     );
 }
+
+#[test]
+fn widgets_table_should_resolve_mouse_positions_to_rows() {
+    let backend = TestBackend::new(20, 6);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TableState::default();
+    terminal
+        .draw(|mut f| {
+            let rows = (0..5).map(|i| Row::Data(vec![format!("Row{}", i)].into_iter()));
+            let table = Table::new(["Head"].iter(), rows)
+                .block(Block::default().borders(Borders::ALL))
+                .widths(&[Constraint::Length(10)])
+                .header_gap(1);
+            f.render_stateful_widget(table, Rect::new(0, 0, 20, 6), &mut state);
+        })
+        .unwrap();
+
+    // Header and gap
+    assert_eq!(state.row_at(1, 1), None);
+    assert_eq!(state.row_at(1, 2), None);
+    assert_eq!(state.row_at(1, 3), Some(0));
+    assert_eq!(state.row_at(18, 4), Some(1));
+
+    let scroll = MouseEvent {
+        kind: MouseEventKind::ScrollDown,
+        column: 1,
+        row: 3,
+        modifiers: KeyModifiers::empty(),
+    };
+    assert!(state.handle_mouse_event(&scroll));
+    assert!(state.handle_mouse_event(&scroll));
+    assert!(state.handle_mouse_event(&scroll));
+    assert!(!state.handle_mouse_event(&scroll));
+    assert_eq!(state.row_at(1, 3), Some(3));
+}
+
+#[test]
+fn widgets_table_should_clamp_the_offset_to_the_given_row_count() {
+    let backend = TestBackend::new(10, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TableState::default();
+    let draw = |terminal: &mut Terminal<TestBackend>, state: &mut TableState, count| {
+        terminal
+            .draw(|mut f| {
+                // Filtered rows do not know their number
+                let rows = (0..10)
+                    .filter(move |&i| i < count)
+                    .map(|i| Row::Data(vec![format!("Row{}", i)].into_iter()));
+                let table = Table::new(["Head"].iter(), rows)
+                    .widths(&[Constraint::Length(10)])
+                    .header_gap(0)
+                    .row_count(count);
+                f.render_stateful_widget(table, f.size(), state);
+            })
+            .unwrap();
+    };
+    draw(&mut terminal, &mut state, 10);
+    state.scroll_down(8);
+    assert_eq!(state.offset(), 8);
+
+    draw(&mut terminal, &mut state, 3);
+    assert_eq!(state.offset(), 1);
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "Head      ",
+        "Row1      ",
+        "Row2      ",
+    ]));
+}
+
+#[test]
+fn widgets_table_should_link_cells_of_linked_spans() {
+    let backend = TestBackend::new(12, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|mut f| {
+            let row =
+                Row::Data(vec![Span::raw("a.rs").link("file:///a.rs"), Span::raw("b")].into_iter());
+            let table = Table::new(["Path", "Size"].iter(), vec![row].into_iter())
+                .widths(&[Constraint::Length(6), Constraint::Length(4)])
+                .header_gap(0);
+            f.render_widget(table, Rect::new(0, 0, 12, 2));
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    for x in 0..4 {
+        assert_eq!(buffer.get(x, 1).link.as_deref(), Some("file:///a.rs"));
+    }
+    for x in 4..12 {
+        assert_eq!(buffer.get(x, 1).link, None);
+    }
+    assert_eq!(buffer.get(0, 0).link, None);
+}
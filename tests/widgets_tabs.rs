@@ -3,7 +3,7 @@ use tui::{
     buffer::Buffer,
     layout::{Margin, Rect},
     symbols,
-    widgets::Tabs,
+    widgets::{Block, Borders, Tabs},
     Terminal,
 };
 
@@ -216,3 +216,26 @@ fn widgets_tabs_should_respect_vertical_margin() {
     );
     test_case(2, 3, vec![" ".repeat(11), " ".repeat(11), " ".repeat(11)]);
 }
+
+#[test]
+fn widgets_tabs_should_resolve_mouse_positions_to_titles() {
+    let titles = ["Tab1", "Tab2", "Tab3"];
+    let tabs = Tabs::default()
+        .block(Block::default().borders(Borders::ALL))
+        .titles(&titles)
+        .margin(Margin {
+            horizontal: 1,
+            vertical: 0,
+        })
+        .divider(symbols::line::VERTICAL);
+    // "│ Tab1 │ Tab2 │ Ta │"
+    let area = Rect::new(0, 0, 20, 3);
+    assert_eq!(tabs.tab_at(area, 2, 1), Some(0));
+    assert_eq!(tabs.tab_at(area, 5, 1), Some(0));
+    assert_eq!(tabs.tab_at(area, 6, 1), None);
+    assert_eq!(tabs.tab_at(area, 7, 1), None);
+    assert_eq!(tabs.tab_at(area, 9, 1), Some(1));
+    assert_eq!(tabs.tab_at(area, 17, 1), Some(2));
+    assert_eq!(tabs.tab_at(area, 18, 1), None);
+    assert_eq!(tabs.tab_at(area, 2, 0), None);
+}
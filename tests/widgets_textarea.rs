@@ -0,0 +1,119 @@
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    event::{KeyCode, KeyEvent, KeyModifiers},
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, TextArea, TextAreaState},
+    Terminal,
+};
+
+#[test]
+fn widgets_textarea_should_edit_lines() {
+    let mut state = TextAreaState::new("first line\nsecond");
+    assert_eq!(state.lines().len(), 2);
+
+    state.handle_key(&KeyEvent::from(KeyCode::End));
+    state.handle_key(&KeyEvent::from(KeyCode::Enter));
+    for c in "new".chars() {
+        state.handle_key(&KeyEvent::from(KeyCode::Char(c)));
+    }
+    assert_eq!(state.text(), "first line\nnew\nsecond");
+    assert_eq!(state.cursor(), (1, 3));
+
+    // Deleting at the start of a line joins it with the previous one
+    state.handle_key(&KeyEvent::from(KeyCode::Home));
+    state.handle_key(&KeyEvent::from(KeyCode::Backspace));
+    assert_eq!(state.text(), "first linenew\nsecond");
+    assert_eq!(state.cursor(), (0, "first line".len()));
+
+    state.handle_key(&KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT));
+    assert_eq!(state.selected_text(), Some("new\nsecond".to_string()));
+    state.insert_str("!\nend");
+    assert_eq!(state.text(), "first line!\nend");
+    assert_eq!(state.cursor(), (1, 3));
+
+    // Consecutive characters are undone together
+    let undo = KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL);
+    let redo = KeyEvent::new(KeyCode::Char('y'), KeyModifiers::CONTROL);
+    state.handle_key(&undo);
+    assert_eq!(state.text(), "first linenew\nsecond");
+    state.handle_key(&undo);
+    assert_eq!(state.text(), "first line\nnew\nsecond");
+    state.handle_key(&undo);
+    assert_eq!(state.text(), "first line\n\nsecond");
+    state.handle_key(&undo);
+    assert_eq!(state.text(), "first line\nsecond");
+    assert_eq!(state.cursor(), (0, "first line".len()));
+    assert!(!state.undo());
+    state.handle_key(&redo);
+    state.handle_key(&redo);
+    state.handle_key(&redo);
+    assert_eq!(state.text(), "first linenew\nsecond");
+}
+
+#[test]
+fn widgets_textarea_should_not_record_deletions_of_nothing() {
+    let mut state = TextAreaState::new("ab");
+    state.delete_backward();
+    assert!(!state.undo());
+
+    state.move_end(false);
+    state.insert_char('c');
+    assert!(state.undo());
+    assert_eq!(state.cursor(), (0, 2));
+    // Deleting at the end of the text keeps the edit to redo
+    state.delete_forward();
+    assert!(state.redo());
+    assert_eq!(state.text(), "abc");
+}
+
+#[test]
+fn widgets_textarea_should_wrap_and_scroll() {
+    let backend = TestBackend::new(12, 4);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TextAreaState::new("hello world foo\nbar");
+    let mut draw = |state: &mut TextAreaState| {
+        terminal
+            .draw(|mut f| {
+                let text_area = TextArea::default()
+                    .block(Block::default().borders(Borders::ALL))
+                    .selection_style(Style::default().bg(Color::Blue));
+                f.render_stateful_widget(text_area, Rect::new(0, 0, 12, 4), state);
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    };
+
+    let buffer = draw(&mut state);
+    assert_eq!(
+        buffer,
+        Buffer::with_lines(vec![
+            "┌──────────┐",
+            "│hello     │",
+            "│world foo │",
+            "└──────────┘",
+        ])
+    );
+    assert_eq!(state.cursor_position(), Some((1, 1)));
+
+    // Moving down follows the wrapped rows and scrolls to keep the cursor visible
+    state.move_right(false);
+    state.move_down(false);
+    assert_eq!(state.cursor(), (0, "hello w".len()));
+    state.move_down(true);
+    assert_eq!(state.cursor(), (1, 1));
+    let buffer = draw(&mut state);
+    let mut expected = Buffer::with_lines(vec![
+        "┌──────────┐",
+        "│world foo │",
+        "│bar       │",
+        "└──────────┘",
+    ]);
+    for x in 2..10 {
+        expected.get_mut(x, 1).set_bg(Color::Blue);
+    }
+    expected.get_mut(1, 2).set_bg(Color::Blue);
+    assert_eq!(buffer, expected);
+    assert_eq!(state.cursor_position(), Some((2, 2)));
+}
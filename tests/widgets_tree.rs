@@ -0,0 +1,74 @@
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    event::{KeyCode, KeyEvent},
+    layout::Rect,
+    style::{Color, Style},
+    text::{Span, Spans},
+    widgets::{Text, Tree, TreeItem, TreeState},
+    Terminal,
+};
+
+fn items(state: &TreeState) -> Vec<TreeItem<'static>> {
+    // The children of "a2" are only built once it is expanded
+    let mut a2 = TreeItem::new("a2", Text::raw("a2")).expandable(true);
+    if state.is_expanded("a2") {
+        a2 = a2.children(vec![TreeItem::new("a21", Text::raw("a21"))]);
+    }
+    vec![
+        TreeItem::new("a", "a").children(vec![TreeItem::new("a1", Text::raw("a1")), a2]),
+        TreeItem::new(
+            "b",
+            Spans::from(vec![Span::styled("b", Style::default().fg(Color::Red))]),
+        ),
+    ]
+}
+
+#[test]
+fn widgets_tree_should_draw_expanded_nodes() {
+    let backend = TestBackend::new(10, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TreeState::default();
+    let mut draw = |state: &mut TreeState| {
+        terminal
+            .draw(|mut f| {
+                let tree =
+                    Tree::new(items(state)).highlight_style(Style::default().bg(Color::Yellow));
+                f.render_stateful_widget(tree, Rect::new(0, 0, 10, 3), state);
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    };
+
+    let buffer = draw(&mut state);
+    let mut expected = Buffer::with_lines(vec!["▸ a       ", "  b       ", "          "]);
+    expected.get_mut(2, 1).set_fg(Color::Red);
+    assert_eq!(buffer, expected);
+
+    state.expand("a");
+    state.expand("a2");
+    state.select(Some("a21"));
+    let buffer = draw(&mut state);
+    let mut expected = Buffer::with_lines(vec!["├─  a1    ", "└─▾ a2    ", "  └─  a21 "]);
+    for x in 4..9 {
+        expected.get_mut(x, 2).set_bg(Color::Yellow);
+    }
+    assert_eq!(buffer, expected);
+    assert_eq!(state.offset(), 1);
+    assert_eq!(state.item_at(5, 0), Some("a1"));
+
+    // Left selects the parent, then collapses it
+    state.handle_key(&KeyEvent::from(KeyCode::Left));
+    assert_eq!(state.selected(), Some("a2"));
+    state.handle_key(&KeyEvent::from(KeyCode::Left));
+    assert!(!state.is_expanded("a2"));
+    state.handle_key(&KeyEvent::from(KeyCode::Down));
+    assert_eq!(state.selected(), Some("b"));
+
+    // Collapsing an ancestor moves the selection to it
+    state.select(Some("a1"));
+    state.collapse("a");
+    draw(&mut state);
+    assert_eq!(state.selected(), Some("a"));
+    assert_eq!(state.offset(), 0);
+}